@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rantz_spatial2d::prelude::*;
+
+const WORLD_SIZE: f32 = 10_000.0;
+const RADIUS: f32 = 150.0;
+
+// Cheap deterministic scatter so runs are comparable without pulling in a rng crate
+fn scatter(count: usize) -> Vec<(Entity, Position2D)> {
+    let mut state = 0x2545_f491_u32;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as f32 / u32::MAX as f32 * WORLD_SIZE
+    };
+    (0..count)
+        .map(|i| (Entity::from_raw(i as u32), Position2D::new(next(), next())))
+        .collect()
+}
+
+fn brute_within_radius(points: &[(Entity, Position2D)], center: Position2D, radius: f32) -> usize {
+    let center = Vec2::from(center);
+    points
+        .iter()
+        .filter(|(_, p)| Vec2::from(p).distance_squared(center) <= radius * radius)
+        .count()
+}
+
+fn brute_k_nearest(points: &[(Entity, Position2D)], center: Position2D, k: usize) -> Vec<Entity> {
+    let center = Vec2::from(center);
+    let mut sorted: Vec<_> = points
+        .iter()
+        .map(|(e, p)| (Vec2::from(p).distance_squared(center), *e))
+        .collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+    sorted.into_iter().take(k).map(|(_, e)| e).collect()
+}
+
+fn build<I: SpatialIndex2D>(mut index: I, points: &[(Entity, Position2D)]) -> I {
+    for (entity, position) in points {
+        index.insert_point(*entity, *position);
+    }
+    index
+}
+
+fn quadtree() -> Quadtree2D {
+    Quadtree2D::new(Rect::new(0.0, 0.0, WORLD_SIZE, WORLD_SIZE))
+}
+
+fn radius_queries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("within_radius");
+    for count in [10_000, 50_000, 100_000] {
+        let points = scatter(count);
+        let grid = build(SpatialGrid2D::new(RADIUS), &points);
+        let tree = build(quadtree(), &points);
+        let center = Position2D::new(WORLD_SIZE * 0.5, WORLD_SIZE * 0.5);
+
+        group.bench_with_input(BenchmarkId::new("brute_force", count), &count, |b, _| {
+            b.iter(|| brute_within_radius(black_box(&points), center, RADIUS))
+        });
+        group.bench_with_input(BenchmarkId::new("grid", count), &count, |b, _| {
+            b.iter(|| black_box(&grid).within_radius(center, RADIUS).len())
+        });
+        group.bench_with_input(BenchmarkId::new("quadtree", count), &count, |b, _| {
+            b.iter(|| black_box(&tree).within_radius(center, RADIUS).len())
+        });
+    }
+    group.finish();
+}
+
+fn k_nearest_queries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("k_nearest");
+    for count in [10_000, 50_000, 100_000] {
+        let points = scatter(count);
+        let grid = build(SpatialGrid2D::new(RADIUS), &points);
+        let tree = build(quadtree(), &points);
+        let center = Position2D::new(WORLD_SIZE * 0.25, WORLD_SIZE * 0.75);
+
+        group.bench_with_input(BenchmarkId::new("brute_force", count), &count, |b, _| {
+            b.iter(|| brute_k_nearest(black_box(&points), center, 8))
+        });
+        group.bench_with_input(BenchmarkId::new("grid", count), &count, |b, _| {
+            b.iter(|| black_box(&grid).k_nearest(center, 8))
+        });
+        group.bench_with_input(BenchmarkId::new("quadtree", count), &count, |b, _| {
+            b.iter(|| black_box(&tree).k_nearest(center, 8))
+        });
+    }
+    group.finish();
+}
+
+fn shuffle<I: SpatialIndex2D>(index: &mut I, points: &[(Entity, Position2D)]) {
+    for (entity, position) in points.iter().step_by(10) {
+        index.insert_point(*entity, *position + Vec2::splat(RADIUS * 0.5));
+        index.insert_point(*entity, *position);
+    }
+}
+
+fn incremental_updates(c: &mut Criterion) {
+    let mut group = c.benchmark_group("update");
+    for count in [10_000, 100_000] {
+        let points = scatter(count);
+        let mut grid = build(SpatialGrid2D::new(RADIUS), &points);
+        let mut tree = build(quadtree(), &points);
+        // Move one in ten entities, the common case for Changed<Position2D>
+        group.bench_with_input(BenchmarkId::new("grid", count), &count, |b, _| {
+            b.iter(|| shuffle(&mut grid, &points))
+        });
+        group.bench_with_input(BenchmarkId::new("quadtree", count), &count, |b, _| {
+            b.iter(|| shuffle(&mut tree, &points))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    radius_queries,
+    k_nearest_queries,
+    incremental_updates
+);
+criterion_main!(benches);
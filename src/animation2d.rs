@@ -0,0 +1,215 @@
+use crate::prelude::*;
+use bevy::{
+    animation::{AnimationTargetId, Interpolation, Keyframes, VariableCurve},
+    prelude::*,
+};
+
+// Bevy can only animate Transform, so the 2D channels travel through it:
+// translation carries Position2D and DrawOrder, scale carries Scale2D and the
+// rotation angle in z. Angles are interpolated as plain numbers, so there are
+// no quaternion flips and multiple turns work.
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct Animated2D {
+    pub position: bool,
+    pub rotation: bool,
+    pub scale: bool,
+    pub draw_order: bool,
+    // What propagate_spatial2d left behind, anything else came from an AnimationPlayer.
+    // Nothing is recorded yet on fresh spawns and scene loads, their Transform is stale.
+    #[reflect(ignore)]
+    propagated: Option<Transform>,
+}
+
+// Only the interpolations whose keyframes can be resampled when channels are merged
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum Interpolation2D {
+    #[default]
+    Linear,
+    Step,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpatialCurves2D {
+    pub interpolation: Interpolation2D,
+    position: Vec<(f32, Vec2)>,
+    rotation: Vec<(f32, f32)>,
+    scale: Vec<(f32, Vec2)>,
+    draw_order: Vec<(f32, f32)>,
+}
+
+impl Animated2D {
+    pub fn all() -> Self {
+        Self {
+            position: true,
+            rotation: true,
+            scale: true,
+            draw_order: true,
+            propagated: None,
+        }
+    }
+
+    pub(crate) fn is_animated(&self, transform: &Transform) -> bool {
+        self.propagated
+            .as_ref()
+            .is_some_and(|propagated| propagated != transform)
+    }
+
+    pub(crate) fn set_propagated(&mut self, transform: Transform) {
+        self.propagated = Some(transform);
+    }
+}
+
+impl SpatialCurves2D {
+    pub fn new(interpolation: Interpolation2D) -> Self {
+        Self {
+            interpolation,
+            position: Vec::new(),
+            rotation: Vec::new(),
+            scale: Vec::new(),
+            draw_order: Vec::new(),
+        }
+    }
+
+    pub fn with_position(mut self, keys: impl IntoIterator<Item = (f32, Position2D)>) -> Self {
+        self.position = sorted(keys.into_iter().map(|(time, value)| (time, value.into())));
+        self
+    }
+
+    // Angles are not wrapped, 0 to TAU is a full turn
+    pub fn with_rotation(mut self, keys: impl IntoIterator<Item = (f32, Radians)>) -> Self {
+        self.rotation = sorted(keys.into_iter().map(|(time, value)| (time, value.to_f32())));
+        self
+    }
+
+    pub fn with_scale(mut self, keys: impl IntoIterator<Item = (f32, Scale2D)>) -> Self {
+        self.scale = sorted(keys.into_iter().map(|(time, value)| (time, value.into())));
+        self
+    }
+
+    pub fn with_draw_order(mut self, keys: impl IntoIterator<Item = (f32, DrawOrder)>) -> Self {
+        self.draw_order = sorted(keys.into_iter().map(|(time, value)| (time, value.into())));
+        self
+    }
+
+    // The component to put next to the AnimationTarget
+    pub fn animated(&self) -> Animated2D {
+        Animated2D {
+            position: !self.position.is_empty(),
+            rotation: !self.rotation.is_empty(),
+            scale: !self.scale.is_empty(),
+            draw_order: !self.draw_order.is_empty(),
+            propagated: None,
+        }
+    }
+
+    pub fn curves(&self) -> Vec<VariableCurve> {
+        let mut curves = Vec::new();
+        if !self.position.is_empty() || !self.draw_order.is_empty() {
+            let times = merged_times(&[&times_of(&self.position), &times_of(&self.draw_order)]);
+            let keyframes = times
+                .iter()
+                .map(|time| {
+                    let position = self.sample(&self.position, *time, Vec2::ZERO);
+                    position.extend(self.sample(&self.draw_order, *time, 0.0))
+                })
+                .collect();
+            curves.push(VariableCurve {
+                keyframe_timestamps: times,
+                keyframes: Keyframes::Translation(keyframes),
+                interpolation: self.interpolation.into(),
+            });
+        }
+        if !self.scale.is_empty() || !self.rotation.is_empty() {
+            let times = merged_times(&[&times_of(&self.scale), &times_of(&self.rotation)]);
+            let keyframes = times
+                .iter()
+                .map(|time| {
+                    let scale = self.sample(&self.scale, *time, Vec2::ONE);
+                    scale.extend(self.sample(&self.rotation, *time, 0.0))
+                })
+                .collect();
+            curves.push(VariableCurve {
+                keyframe_timestamps: times,
+                keyframes: Keyframes::Scale(keyframes),
+                interpolation: self.interpolation.into(),
+            });
+        }
+        curves
+    }
+
+    pub fn add_to_clip(&self, clip: &mut AnimationClip, target: AnimationTargetId) {
+        for curve in self.curves() {
+            clip.add_curve_to_target(target, curve);
+        }
+    }
+
+    fn sample<T>(&self, keys: &[(f32, T)], time: f32, empty: T) -> T
+    where
+        T: Copy
+            + std::ops::Add<Output = T>
+            + std::ops::Sub<Output = T>
+            + std::ops::Mul<f32, Output = T>,
+    {
+        let Some(first) = keys.first() else {
+            return empty;
+        };
+        let next = keys.partition_point(|(key_time, _)| *key_time <= time);
+        if next == 0 {
+            return first.1;
+        }
+        if next == keys.len() {
+            return keys[next - 1].1;
+        }
+        let (from_time, from) = keys[next - 1];
+        let (to_time, to) = keys[next];
+        match self.interpolation {
+            Interpolation2D::Step => from,
+            _ => from + (to - from) * ((time - from_time) / (to_time - from_time)),
+        }
+    }
+}
+
+fn sorted<T>(keys: impl Iterator<Item = (f32, T)>) -> Vec<(f32, T)> {
+    let mut keys: Vec<_> = keys.collect();
+    keys.sort_by(|a, b| a.0.total_cmp(&b.0));
+    keys
+}
+
+fn times_of<T>(keys: &[(f32, T)]) -> Vec<f32> {
+    keys.iter().map(|(time, _)| *time).collect()
+}
+
+fn merged_times(channels: &[&[f32]]) -> Vec<f32> {
+    let mut times: Vec<f32> = channels
+        .iter()
+        .flat_map(|times| times.iter().copied())
+        .collect();
+    times.sort_by(f32::total_cmp);
+    times.dedup();
+    times
+}
+
+mod default {
+    use super::*;
+    impl Default for SpatialCurves2D {
+        fn default() -> Self {
+            Self::new(Interpolation2D::Linear)
+        }
+    }
+}
+
+mod into {
+    use super::*;
+    impl From<Interpolation2D> for Interpolation {
+        fn from(interpolation: Interpolation2D) -> Self {
+            match interpolation {
+                Interpolation2D::Linear => Interpolation::Linear,
+                Interpolation2D::Step => Interpolation::Step,
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+// Runs after bevy's animate_targets and before anything reads the 2D components
+pub fn sync_animated2d(
+    mut query: Query<(
+        &Transform,
+        &Animated2D,
+        &mut Position2D,
+        &mut Rotation2D,
+        &mut Scale2D,
+        &mut DrawOrder,
+    )>,
+) {
+    query.par_iter_mut().for_each(
+        |(transform, animated, mut position, mut rotation, mut scale, mut draw_order)| {
+            if !animated.is_animated(transform) {
+                return;
+            }
+            if animated.position {
+                position.set_if_neq(transform.translation.truncate().into());
+            }
+            if animated.draw_order {
+                draw_order.set_if_neq(DrawOrder::new(transform.translation.z));
+            }
+            if animated.scale && transform.scale.x != 0.0 && transform.scale.y != 0.0 {
+                scale.set_if_neq(Scale2D::from_vec(transform.scale.truncate()));
+            }
+            // The angle rides in scale.z, see Animated2D, propagation puts 1 back afterwards
+            if animated.rotation {
+                rotation.set_if_neq(Rotation2D::from_f32_radians(transform.scale.z));
+            }
+        },
+    );
+}
+
+pub fn record_animated2d(mut query: Query<(&Transform, &mut Animated2D)>) {
+    query.par_iter_mut().for_each(|(transform, mut animated)| {
+        animated
+            .bypass_change_detection()
+            .set_propagated(*transform);
+    });
+}
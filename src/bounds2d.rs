@@ -0,0 +1,332 @@
+use crate::prelude::*;
+use bevy::{math::Affine2, prelude::*};
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum Bounds2D {
+    Rect { half_size: Vec2, offset: Vec2 },
+    Circle { radius: f32, offset: Vec2 },
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Obb2D {
+    pub center: Vec2,
+    pub half_size: Vec2,
+    pub rotation: Rotation2D,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct WorldBounds2D {
+    pub aabb: Rect,
+    pub obb: Obb2D,
+    shape: Bounds2D,
+    world_from_local: Affine2,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default, Component, Reflect, Deref, DerefMut)]
+#[reflect(Component, Default)]
+pub struct HierarchyBounds2D(pub Option<Rect>);
+
+impl Bounds2D {
+    pub fn rect(size: Vec2) -> Self {
+        Self::Rect {
+            half_size: size * 0.5,
+            offset: Vec2::ZERO,
+        }
+    }
+
+    pub fn circle(radius: f32) -> Self {
+        Self::Circle {
+            radius,
+            offset: Vec2::ZERO,
+        }
+    }
+
+    pub fn offset(&self) -> Vec2 {
+        match self {
+            Self::Rect { offset, .. } | Self::Circle { offset, .. } => *offset,
+        }
+    }
+
+    pub fn with_offset(mut self, new_offset: Vec2) -> Self {
+        match &mut self {
+            Self::Rect { offset, .. } | Self::Circle { offset, .. } => *offset = new_offset,
+        }
+        self
+    }
+
+    // Pivot is normalized the same way as sprite anchors, (-0.5, -0.5) is the bottom left
+    pub fn with_pivot(self, pivot: Vec2) -> Self {
+        let size = self.local_rect().size();
+        self.with_offset(-pivot * size)
+    }
+
+    pub fn local_rect(&self) -> Rect {
+        match *self {
+            Self::Rect { half_size, offset } => Rect::from_center_half_size(offset, half_size),
+            Self::Circle { radius, offset } => {
+                Rect::from_center_half_size(offset, Vec2::splat(radius))
+            }
+        }
+    }
+
+    pub fn contains_local(&self, point: Vec2) -> bool {
+        match *self {
+            Self::Rect { half_size, offset } => {
+                let local = (point - offset).abs();
+                local.x <= half_size.x && local.y <= half_size.y
+            }
+            Self::Circle { radius, offset } => point.distance_squared(offset) <= radius * radius,
+        }
+    }
+
+    pub fn intersects_local_polygon(&self, polygon: &[Vec2]) -> bool {
+        match *self {
+            Self::Rect { .. } => {
+                let corners = rect_corners(self.local_rect());
+                !separated(&corners, polygon) && !separated(polygon, &corners)
+            }
+            Self::Circle { radius, offset } => {
+                point_in_convex(polygon, offset)
+                    || edges(polygon).any(|(start, end)| {
+                        closest_on_segment(start, end, offset).distance_squared(offset)
+                            <= radius * radius
+                    })
+            }
+        }
+    }
+}
+
+impl Obb2D {
+    pub fn axes(&self) -> (Vec2, Vec2) {
+        let x = Vec2::from(self.rotation.radians());
+        (x * self.half_size.x, x.perp() * self.half_size.y)
+    }
+
+    pub fn corners(&self) -> [Vec2; 4] {
+        let (x, y) = self.axes();
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        let local = (point - self.center).rotate(Vec2::from(-self.rotation.radians()));
+        local.x.abs() <= self.half_size.x && local.y.abs() <= self.half_size.y
+    }
+}
+
+impl WorldBounds2D {
+    pub fn from_transform(bounds: &Bounds2D, transform: &GlobalTransform) -> Self {
+        let affine = transform.affine();
+        Self::from_affine(
+            bounds,
+            Affine2::from_mat2_translation(
+                Mat2::from_cols(
+                    affine.matrix3.x_axis.truncate(),
+                    affine.matrix3.y_axis.truncate(),
+                ),
+                affine.translation.truncate(),
+            ),
+        )
+    }
+
+    pub fn from_affine(bounds: &Bounds2D, world_from_local: Affine2) -> Self {
+        let matrix = world_from_local.matrix2;
+        let center = world_from_local.transform_point2(bounds.offset());
+        let (aabb, x_axis, y_axis) = match *bounds {
+            Bounds2D::Rect { half_size, .. } => {
+                let x_axis = matrix.x_axis * half_size.x;
+                let y_axis = matrix.y_axis * half_size.y;
+                let extent = x_axis.abs() + y_axis.abs();
+                (Rect::from_center_half_size(center, extent), x_axis, y_axis)
+            }
+            Bounds2D::Circle { radius, .. } => {
+                // Half extents of an ellipse are the lengths of the rows of its matrix
+                let extent = Vec2::new(matrix.row(0).length(), matrix.row(1).length()) * radius;
+                (
+                    Rect::from_center_half_size(center, extent),
+                    matrix.x_axis * radius,
+                    matrix.y_axis * radius,
+                )
+            }
+        };
+
+        Self {
+            aabb,
+            obb: Obb2D {
+                center,
+                half_size: Vec2::new(x_axis.length(), y_axis.length()),
+                rotation: Rotation2D::from_f32_radians(x_axis.to_angle()),
+            },
+            shape: *bounds,
+            world_from_local,
+        }
+    }
+
+    pub fn shape(&self) -> Bounds2D {
+        self.shape
+    }
+
+    pub fn world_from_local(&self) -> Affine2 {
+        self.world_from_local
+    }
+
+    pub fn to_local(&self, point: Vec2) -> Vec2 {
+        self.world_from_local.inverse().transform_point2(point)
+    }
+
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.aabb.contains(point) && self.shape.contains_local(self.to_local(point))
+    }
+
+    // Returns the distance along `direction` and the world space surface normal,
+    // `direction` is expected to be normalized
+    pub fn ray_intersection(&self, origin: Vec2, direction: Vec2) -> Option<(f32, Vec2)> {
+        let local_from_world = self.world_from_local.inverse();
+        let local_origin = local_from_world.transform_point2(origin);
+        let local_direction = local_from_world.transform_vector2(direction);
+        let (distance, local_normal) = match self.shape {
+            Bounds2D::Rect { half_size, offset } => {
+                ray_rect(local_origin - offset, local_direction, half_size)?
+            }
+            Bounds2D::Circle { radius, offset } => {
+                ray_circle(local_origin - offset, local_direction, radius)?
+            }
+        };
+        let normal = match local_normal {
+            Some(normal) => (local_from_world.matrix2.transpose() * normal).normalize_or_zero(),
+            None => -direction,
+        };
+        Some((distance, normal))
+    }
+
+    pub fn intersects_rect(&self, rect: Rect) -> bool {
+        // Touching counts, an empty intersection would miss zero sized rects
+        if !(self.aabb.min.cmple(rect.max).all() && self.aabb.max.cmpge(rect.min).all()) {
+            return false;
+        }
+        let local_from_world = self.world_from_local.inverse();
+        let polygon = rect_corners(rect).map(|corner| local_from_world.transform_point2(corner));
+        self.shape.intersects_local_polygon(&polygon)
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for Bounds2D {
+        fn default() -> Self {
+            Self::rect(Vec2::ONE)
+        }
+    }
+
+    impl Default for WorldBounds2D {
+        fn default() -> Self {
+            Self::from_affine(&Bounds2D::default(), Affine2::IDENTITY)
+        }
+    }
+}
+
+fn rect_corners(rect: Rect) -> [Vec2; 4] {
+    [
+        rect.min,
+        Vec2::new(rect.max.x, rect.min.y),
+        rect.max,
+        Vec2::new(rect.min.x, rect.max.y),
+    ]
+}
+
+fn edges(polygon: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    polygon
+        .iter()
+        .copied()
+        .zip(polygon.iter().copied().cycle().skip(1))
+}
+
+// True when one of the edges of `a` is a separating axis
+fn separated(a: &[Vec2], b: &[Vec2]) -> bool {
+    edges(a).any(|(start, end)| {
+        let axis = (end - start).perp();
+        let project = |points: &[Vec2]| {
+            points.iter().fold((f32::MAX, f32::MIN), |(min, max), p| {
+                let d = axis.dot(*p);
+                (min.min(d), max.max(d))
+            })
+        };
+        let (a_min, a_max) = project(a);
+        let (b_min, b_max) = project(b);
+        a_max < b_min || b_max < a_min
+    })
+}
+
+fn point_in_convex(polygon: &[Vec2], point: Vec2) -> bool {
+    let mut sign = 0.0;
+    for (start, end) in edges(polygon) {
+        let cross = (end - start).perp_dot(point - start);
+        if cross != 0.0 {
+            if sign != 0.0 && cross.signum() != sign {
+                return false;
+            }
+            sign = cross.signum();
+        }
+    }
+    true
+}
+
+// Normal is None when the ray starts inside the shape
+fn ray_rect(origin: Vec2, direction: Vec2, half_size: Vec2) -> Option<(f32, Option<Vec2>)> {
+    if origin.abs().cmple(half_size).all() {
+        return Some((0.0, None));
+    }
+    let mut near = f32::MIN;
+    let mut far = f32::MAX;
+    let mut normal = Vec2::ZERO;
+    for axis in 0..2 {
+        if direction[axis] == 0.0 {
+            if origin[axis].abs() > half_size[axis] {
+                return None;
+            }
+            continue;
+        }
+        let t0 = (-half_size[axis] - origin[axis]) / direction[axis];
+        let t1 = (half_size[axis] - origin[axis]) / direction[axis];
+        let (t0, t1) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        if t0 > near {
+            near = t0;
+            normal = Vec2::ZERO;
+            normal[axis] = -direction[axis].signum();
+        }
+        far = far.min(t1);
+    }
+    (near <= far && near >= 0.0).then_some((near, Some(normal)))
+}
+
+fn ray_circle(origin: Vec2, direction: Vec2, radius: f32) -> Option<(f32, Option<Vec2>)> {
+    let c = origin.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some((0.0, None));
+    }
+    let a = direction.length_squared();
+    let b = origin.dot(direction);
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 || b > 0.0 {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / a;
+    Some((t, Some(origin + direction * t)))
+}
+
+pub(crate) fn closest_on_segment(start: Vec2, end: Vec2, point: Vec2) -> Vec2 {
+    let segment = end - start;
+    let t = (point - start).dot(segment) / segment.length_squared().max(f32::EPSILON);
+    start + segment * t.clamp(0.0, 1.0)
+}
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+
+pub fn update_world_bounds2d(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Bounds2D,
+            &GlobalTransform,
+            Option<&mut WorldBounds2D>,
+        ),
+        Or<(
+            Changed<Bounds2D>,
+            Changed<GlobalTransform>,
+            Without<WorldBounds2D>,
+        )>,
+    >,
+    mut removed: RemovedComponents<Bounds2D>,
+) {
+    for entity in removed.read() {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.remove::<WorldBounds2D>();
+        }
+    }
+    for (entity, bounds, transform, world_bounds) in query.iter_mut() {
+        let new_bounds = WorldBounds2D::from_transform(bounds, transform);
+        match world_bounds {
+            Some(mut world_bounds) => {
+                world_bounds.set_if_neq(new_bounds);
+            }
+            None => {
+                commands.entity(entity).insert(new_bounds);
+            }
+        }
+    }
+}
+
+pub fn update_hierarchy_bounds2d(
+    mut hierarchy: Query<(Entity, &mut HierarchyBounds2D)>,
+    bounds: Query<(Entity, &WorldBounds2D)>,
+    parents: Query<&Parent>,
+) {
+    let mut unions: HashMap<Entity, Rect> = HashMap::default();
+    for (entity, world_bounds) in bounds.iter() {
+        let mut current = Some(entity);
+        while let Some(ancestor) = current {
+            if hierarchy.contains(ancestor) {
+                unions
+                    .entry(ancestor)
+                    .and_modify(|rect| *rect = rect.union(world_bounds.aabb))
+                    .or_insert(world_bounds.aabb);
+            }
+            current = parents.get(ancestor).ok().map(Parent::get);
+        }
+    }
+    for (entity, mut hierarchy_bounds) in hierarchy.iter_mut() {
+        hierarchy_bounds.set_if_neq(HierarchyBounds2D(unions.get(&entity).copied()));
+    }
+}
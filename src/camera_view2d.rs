@@ -0,0 +1,169 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::system::SystemParam, math::Affine2, prelude::*, render::camera::NormalizedRenderTarget,
+    window::PrimaryWindow,
+};
+
+// Everything needed to move between viewport pixels, NDC and world space for a 2D camera.
+// Viewport pixels are logical and y-down like cursor positions, NDC and world are y-up.
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+pub struct CameraView2D {
+    pub viewport: Rect,
+    pub area: Rect,
+    pub world_from_camera: Affine2,
+}
+
+#[derive(SystemParam)]
+pub struct Cameras2D<'w, 's> {
+    cameras: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Camera,
+            &'static OrthographicProjection,
+            &'static GlobalTransform,
+        ),
+    >,
+    primary_window: Query<'w, 's, (Entity, &'static Window), With<PrimaryWindow>>,
+}
+
+impl CameraView2D {
+    pub fn new(viewport: Rect, area: Rect, world_from_camera: Affine2) -> Self {
+        Self {
+            viewport,
+            area,
+            world_from_camera,
+        }
+    }
+
+    // A camera that doesn't need a window, scale behaves like OrthographicProjection::scale
+    pub fn synthetic(
+        viewport_size: Vec2,
+        position: Position2D,
+        rotation: Rotation2D,
+        scale: f32,
+    ) -> Self {
+        Self::new(
+            Rect::from_corners(Vec2::ZERO, viewport_size),
+            Rect::from_center_size(Vec2::ZERO, viewport_size * scale),
+            Affine2::from_angle_translation(rotation.radians().into(), position.into()),
+        )
+    }
+
+    pub fn from_camera(
+        camera: &Camera,
+        projection: &OrthographicProjection,
+        transform: &GlobalTransform,
+    ) -> Option<Self> {
+        let affine = transform.affine();
+        Some(Self::new(
+            camera.logical_viewport_rect()?,
+            projection.area,
+            Affine2::from_mat2_translation(
+                Mat2::from_cols(
+                    affine.matrix3.x_axis.truncate(),
+                    affine.matrix3.y_axis.truncate(),
+                ),
+                affine.translation.truncate(),
+            ),
+        ))
+    }
+
+    pub fn viewport_to_ndc(&self, viewport: Vec2) -> Vec2 {
+        let normalized = (viewport - self.viewport.min) / self.viewport.size();
+        Vec2::new(normalized.x * 2.0 - 1.0, 1.0 - normalized.y * 2.0)
+    }
+
+    pub fn ndc_to_viewport(&self, ndc: Vec2) -> Vec2 {
+        let normalized = Vec2::new(ndc.x + 1.0, 1.0 - ndc.y) * 0.5;
+        self.viewport.min + normalized * self.viewport.size()
+    }
+
+    pub fn ndc_to_world(&self, ndc: Vec2) -> Position2D {
+        let local = self.area.min + (ndc + 1.0) * 0.5 * self.area.size();
+        self.world_from_camera.transform_point2(local).into()
+    }
+
+    pub fn world_to_ndc(&self, world: Position2D) -> Vec2 {
+        let local = self
+            .world_from_camera
+            .inverse()
+            .transform_point2(world.into());
+        (local - self.area.min) / self.area.size() * 2.0 - 1.0
+    }
+
+    pub fn viewport_to_world(&self, viewport: Vec2) -> Position2D {
+        self.ndc_to_world(self.viewport_to_ndc(viewport))
+    }
+
+    pub fn world_to_viewport(&self, world: Position2D) -> Vec2 {
+        self.ndc_to_viewport(self.world_to_ndc(world))
+    }
+
+    pub fn contains_world(&self, world: Position2D) -> bool {
+        let ndc = self.world_to_ndc(world);
+        ndc.cmpge(Vec2::NEG_ONE).all() && ndc.cmple(Vec2::ONE).all()
+    }
+
+    // Axis aligned bounds of what the camera can see, larger than the view when rotated
+    pub fn visible_rect(&self) -> Rect {
+        [
+            Vec2::NEG_ONE,
+            Vec2::new(1.0, -1.0),
+            Vec2::ONE,
+            Vec2::new(-1.0, 1.0),
+        ]
+        .into_iter()
+        .map(|ndc| Vec2::from(self.ndc_to_world(ndc)))
+        .fold(Rect::EMPTY, |rect, corner| rect.union_point(corner))
+    }
+}
+
+impl<'w, 's> Cameras2D<'w, 's> {
+    pub fn view(&self, camera: Entity) -> Option<CameraView2D> {
+        let (_, camera, projection, transform) = self.cameras.get(camera).ok()?;
+        CameraView2D::from_camera(camera, projection, transform)
+    }
+
+    // The active camera with the highest order, which is the one drawn on top
+    pub fn main_view(&self) -> Option<CameraView2D> {
+        self.top_view(|_, _| true)
+    }
+
+    fn top_view(&self, filter: impl Fn(&Camera, &CameraView2D) -> bool) -> Option<CameraView2D> {
+        self.cameras
+            .iter()
+            .filter(|(_, camera, _, _)| camera.is_active)
+            .filter_map(|(entity, camera, projection, transform)| {
+                let view = CameraView2D::from_camera(camera, projection, transform)?;
+                filter(camera, &view).then_some(((camera.order, entity), view))
+            })
+            .max_by_key(|(order, _)| *order)
+            .map(|(_, view)| view)
+    }
+
+    pub fn viewport_to_world(&self, camera: Entity, viewport: Vec2) -> Option<Position2D> {
+        self.view(camera)
+            .map(|view| view.viewport_to_world(viewport))
+    }
+
+    pub fn world_to_viewport(&self, camera: Entity, world: Position2D) -> Option<Vec2> {
+        self.view(camera).map(|view| view.world_to_viewport(world))
+    }
+
+    // Cameras drawing to an image or another window never see this cursor, nor do ones
+    // whose viewport is elsewhere on the window
+    pub fn cursor_position(&self) -> Option<Position2D> {
+        let (window, primary) = self.primary_window.get_single().ok()?;
+        let cursor = primary.cursor_position()?;
+        let view = self.top_view(|camera, view| {
+            view.viewport.contains(cursor)
+                && matches!(
+                    camera.target.normalize(Some(window)),
+                    Some(NormalizedRenderTarget::Window(target)) if target.entity() == window
+                )
+        })?;
+        Some(view.viewport_to_world(cursor))
+    }
+}
@@ -0,0 +1,163 @@
+use crate::bounds2d::closest_on_segment;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum ClampRegion2D {
+    Rect(Rect),
+    Circle { center: Vec2, radius: f32 },
+    Polygon(Vec<Vec2>),
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum ClampSpace2D {
+    World,
+    // Same space as Position2D
+    #[default]
+    Parent,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum ClampMode2D {
+    #[default]
+    Hard,
+    // Eases back inside, higher stiffness pushes back faster
+    Soft {
+        stiffness: f32,
+    },
+}
+
+#[derive(Clone, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct ClampPosition2D {
+    pub region: ClampRegion2D,
+    pub space: ClampSpace2D,
+    pub mode: ClampMode2D,
+}
+
+// Limits the entity's own Rotation2D, min to max counter clockwise
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct ClampRotation2D {
+    pub min: Radians,
+    pub max: Radians,
+}
+
+impl ClampRegion2D {
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        match self {
+            Self::Rect(rect) => point.clamp(rect.min, rect.max),
+            Self::Circle { center, radius } => {
+                *center + (point - *center).clamp_length_max(*radius)
+            }
+            Self::Polygon(polygon) => {
+                if polygon.len() < 3 || contains_polygon(polygon, point) {
+                    return point;
+                }
+                polygon
+                    .iter()
+                    .zip(polygon.iter().cycle().skip(1))
+                    .map(|(start, end)| closest_on_segment(*start, *end, point))
+                    .min_by(|a, b| {
+                        a.distance_squared(point)
+                            .total_cmp(&b.distance_squared(point))
+                    })
+                    .unwrap_or(point)
+            }
+        }
+    }
+}
+
+impl ClampPosition2D {
+    pub fn new(region: ClampRegion2D) -> Self {
+        Self {
+            region,
+            space: ClampSpace2D::Parent,
+            mode: ClampMode2D::Hard,
+        }
+    }
+
+    pub fn rect(rect: Rect) -> Self {
+        Self::new(ClampRegion2D::Rect(rect))
+    }
+
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Self::new(ClampRegion2D::Circle { center, radius })
+    }
+
+    pub fn polygon(points: impl IntoIterator<Item = Vec2>) -> Self {
+        Self::new(ClampRegion2D::Polygon(points.into_iter().collect()))
+    }
+
+    pub fn in_world(mut self) -> Self {
+        self.space = ClampSpace2D::World;
+        self
+    }
+
+    pub fn soft(mut self, stiffness: f32) -> Self {
+        self.mode = ClampMode2D::Soft { stiffness };
+        self
+    }
+
+    pub fn apply(&self, point: Vec2, delta: f32) -> Vec2 {
+        let closest = self.region.closest_point(point);
+        match self.mode {
+            ClampMode2D::Hard => closest,
+            ClampMode2D::Soft { stiffness } => {
+                point.lerp(closest, 1.0 - (-stiffness * delta).exp())
+            }
+        }
+    }
+}
+
+impl ClampRotation2D {
+    pub fn new(min: Radians, max: Radians) -> Self {
+        assert!(min.to_f32() <= max.to_f32());
+        Self { min, max }
+    }
+
+    pub fn degrees(min: f32, max: f32) -> Self {
+        Self::new(
+            Radians::from_f32(min.to_radians()),
+            Radians::from_f32(max.to_radians()),
+        )
+    }
+
+    // Outside the range snaps to whichever limit is the shorter arc away
+    pub fn clamp(&self, angle: Radians) -> Radians {
+        let mid = (self.min.to_f32() + self.max.to_f32()) * 0.5;
+        let unwrapped = mid + Radians::from_f32(mid).shortest_to(angle).to_f32();
+        Radians::from_f32(unwrapped.clamp(self.min.to_f32(), self.max.to_f32()))
+    }
+
+    pub fn contains(&self, angle: Radians) -> bool {
+        let clamped = self.clamp(angle);
+        clamped.shortest_to(angle).to_f32().abs() <= f32::EPSILON
+    }
+}
+
+// Even-odd rule, so concave polygons work too
+fn contains_polygon(polygon: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    for (start, end) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+        if (start.y > point.y) != (end.y > point.y) {
+            let x = start.x + (point.y - start.y) / (end.y - start.y) * (end.x - start.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
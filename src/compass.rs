@@ -1,7 +1,9 @@
-use bevy::prelude::{Component, Reflect};
+use bevy::prelude::*;
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Component, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub enum Compass {
     #[default]
     N,
@@ -10,6 +12,10 @@ pub enum Compass {
     W,
 }
 
+impl Compass {
+    pub const ALL: [Self; 4] = [Self::N, Self::E, Self::S, Self::W];
+}
+
 mod from {
     use super::Compass;
     use crate::{
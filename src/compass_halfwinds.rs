@@ -1,7 +1,9 @@
-use bevy::prelude::{Component, Reflect};
+use bevy::prelude::*;
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Component, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub enum CompassHalfwinds {
     #[default]
     N,
@@ -22,6 +24,27 @@ pub enum CompassHalfwinds {
     NNW,
 }
 
+impl CompassHalfwinds {
+    pub const ALL: [Self; 16] = [
+        Self::N,
+        Self::NNE,
+        Self::NE,
+        Self::ENE,
+        Self::E,
+        Self::ESE,
+        Self::SE,
+        Self::SSE,
+        Self::S,
+        Self::SSW,
+        Self::SW,
+        Self::WSW,
+        Self::W,
+        Self::WNW,
+        Self::NW,
+        Self::NNW,
+    ];
+}
+
 mod from {
     use super::CompassHalfwinds;
     use crate::{
@@ -1,7 +1,9 @@
-use bevy::prelude::{Component, Reflect};
+use bevy::prelude::*;
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Component, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub enum CompassRose {
     #[default]
     N,
@@ -14,6 +16,19 @@ pub enum CompassRose {
     NW,
 }
 
+impl CompassRose {
+    pub const ALL: [Self; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+}
+
 mod from {
     use super::CompassRose;
     use crate::{
@@ -0,0 +1,165 @@
+use crate::prelude::*;
+use bevy::{math::Affine2, prelude::*, utils::HashMap};
+
+pub fn look_at2d(
+    time: Res<Time>,
+    look_ats: Query<(Entity, &LookAt2D, &RotationPropagation)>,
+    mut set: ParamSet<(GlobalSpatial2D, Query<(&mut Rotation2D, &LookAt2D)>)>,
+    mut desired: Local<Vec<(Entity, Radians)>>,
+) {
+    desired.clear();
+    let spatial = set.p0();
+    for (entity, look_at, r_prop) in look_ats.iter() {
+        let target = match look_at.target {
+            LookTarget2D::Entity(target) => spatial.world_position(target),
+            LookTarget2D::Position(position) => Some(position),
+        };
+        let (Some(target), Some(origin)) = (target, spatial.world_position(entity)) else {
+            continue;
+        };
+        let direction = Vec2::from(target) - Vec2::from(origin);
+        if direction == Vec2::ZERO {
+            continue;
+        }
+        let world = Radians::from_f32(direction.to_angle()) + look_at.offset;
+        // Relative rotations are added to the parent's world facing, absolute ones replace it
+        let local = match r_prop {
+            RotationPropagation::Relative => world - spatial.parent_rotation(entity).radians(),
+            RotationPropagation::Absolute => world,
+        };
+        desired.push((entity, local));
+    }
+
+    let delta = time.delta_seconds();
+    let mut rotations = set.p1();
+    for (entity, local) in desired.iter() {
+        let Ok((mut rotation, look_at)) = rotations.get_mut(*entity) else {
+            continue;
+        };
+        let new_rotation = Rotation2D::from(look_at.step(rotation.radians(), *local, delta));
+        rotation.set_if_neq(new_rotation);
+    }
+}
+
+pub fn follow2d(
+    time: Res<Time>,
+    parents: Query<&Parent>,
+    mut set: ParamSet<(
+        GlobalSpatial2D,
+        Query<(Entity, &mut Position2D, &mut Follow2D)>,
+    )>,
+    mut order: Local<Vec<(usize, Entity)>>,
+) {
+    // Followers of followers go after what they depend on so chains settle in one frame
+    let followers: HashMap<Entity, Entity> = set
+        .p1()
+        .iter()
+        .map(|(entity, _, follow)| (entity, follow.target))
+        .collect();
+    let mut depths = HashMap::default();
+    order.clear();
+    for entity in followers.keys() {
+        let depth = dependency_depth(*entity, &followers, &parents, &mut depths);
+        order.push((depth, *entity));
+    }
+    order.sort();
+
+    let delta = time.delta_seconds();
+    for (_, entity) in order.iter() {
+        let spatial = set.p0();
+        let target = followers[entity];
+        let (Some(current), Some(target_affine)) = (
+            spatial.world_position(*entity),
+            spatial.world_affine(target),
+        ) else {
+            continue;
+        };
+        let parent_from_world = spatial.parent_affine(*entity).inverse();
+
+        let mut followers = set.p1();
+        let Ok((_, mut position, mut follow)) = followers.get_mut(*entity) else {
+            continue;
+        };
+        let goal = match follow.offset {
+            FollowOffset2D::World(offset) => target_affine.translation + offset,
+            FollowOffset2D::TargetLocal(offset) => target_affine.transform_point2(offset),
+        };
+        let next = follow.step(current.into(), goal, delta);
+        let next = parent_from_world.transform_point2(next);
+        let locks = follow.lock_axes;
+        let new_position = Position2D::new(
+            if locks.x { position.x } else { next.x },
+            if locks.y { position.y } else { next.y },
+        );
+        position.set_if_neq(new_position);
+    }
+}
+
+// Anything that moves the target, or our own parent space, has to be resolved first
+pub(crate) fn dependency_depth(
+    entity: Entity,
+    targets: &HashMap<Entity, Entity>,
+    parents: &Query<&Parent>,
+    depths: &mut HashMap<Entity, usize>,
+) -> usize {
+    if let Some(depth) = depths.get(&entity) {
+        return *depth;
+    }
+    // Marked before recursing so cycles, which have no valid order, stop here and every
+    // entity is only ever resolved once
+    depths.insert(entity, 0);
+    let mut dependencies: Vec<Entity> = parents.iter_ancestors(entity).collect();
+    if let Some(target) = targets.get(&entity) {
+        dependencies.push(*target);
+        dependencies.extend(parents.iter_ancestors(*target));
+    }
+    let depth = dependencies
+        .into_iter()
+        .filter(|dependency| targets.contains_key(dependency))
+        .map(|dependency| dependency_depth(dependency, targets, parents, depths) + 1)
+        .max()
+        .unwrap_or(0);
+    depths.insert(entity, depth);
+    depth
+}
+
+pub fn clamp_position2d(
+    time: Res<Time>,
+    clamps: Query<(Entity, &ClampPosition2D)>,
+    mut set: ParamSet<(
+        GlobalSpatial2D,
+        Query<(Entity, &mut Position2D, &ClampPosition2D)>,
+    )>,
+    mut parent_affines: Local<HashMap<Entity, Affine2>>,
+) {
+    parent_affines.clear();
+    let spatial = set.p0();
+    for (entity, clamp) in clamps.iter() {
+        if clamp.space == ClampSpace2D::World {
+            parent_affines.insert(entity, spatial.parent_affine(entity));
+        }
+    }
+
+    let delta = time.delta_seconds();
+    set.p1()
+        .par_iter_mut()
+        .for_each(|(entity, mut position, clamp)| {
+            let new_position = match parent_affines.get(&entity) {
+                Some(world_from_parent) => {
+                    let world = world_from_parent.transform_point2((*position).into());
+                    let clamped = clamp.apply(world, delta);
+                    world_from_parent.inverse().transform_point2(clamped)
+                }
+                None => clamp.apply((*position).into(), delta),
+            };
+            position.set_if_neq(new_position.into());
+        });
+}
+
+pub fn clamp_rotation2d(mut query: Query<(&mut Rotation2D, &ClampRotation2D)>) {
+    query.par_iter_mut().for_each(|(mut rotation, clamp)| {
+        if !clamp.contains(rotation.radians()) {
+            *rotation = clamp.clamp(rotation.radians()).into();
+        }
+    });
+}
@@ -3,6 +3,8 @@ use bevy::prelude::*;
 
 #[derive(Default, Clone, Copy, PartialEq, Debug, Reflect, Deref, DerefMut)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct Degrees(f32);
 
 impl Degrees {
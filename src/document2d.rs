@@ -0,0 +1,188 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub const SPATIAL_DOCUMENT_VERSION: u32 = 1;
+
+// Stable on-disk form of a 2D hierarchy. Everything but the version is optional,
+// missing fields fall back to the same defaults SpatialBundle2D uses.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SpatialDocument2D {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roots: Vec<SpatialNode2D>,
+}
+
+#[derive(Default, Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SpatialNode2D {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub position: Position2D,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation: Rotation2D,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub scale: Scale2D,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub draw_order: DrawOrder,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub rotation_propagation: RotationPropagation,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub position_propagation: PositionPropagation,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub scale_propagation: ScalePropagation,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<SpatialNode2D>,
+}
+
+#[derive(Debug)]
+pub enum SpatialDocumentError {
+    UnsupportedVersion(u32),
+    #[cfg(feature = "ron")]
+    Ron(ron::Error),
+    #[cfg(feature = "ron")]
+    RonParse(ron::error::SpannedError),
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+impl SpatialDocument2D {
+    pub fn new(roots: Vec<SpatialNode2D>) -> Self {
+        Self {
+            version: SPATIAL_DOCUMENT_VERSION,
+            roots,
+        }
+    }
+
+    // Children without spatial components are skipped along with their descendants
+    pub fn from_world(world: &World, roots: &[Entity]) -> Self {
+        Self::new(
+            roots
+                .iter()
+                .filter_map(|root| SpatialNode2D::from_world(world, *root))
+                .collect(),
+        )
+    }
+
+    pub fn spawn(&self, world: &mut World) -> Vec<Entity> {
+        self.roots.iter().map(|root| root.spawn(world)).collect()
+    }
+
+    pub fn check_version(&self) -> Result<(), SpatialDocumentError> {
+        if self.version > SPATIAL_DOCUMENT_VERSION {
+            return Err(SpatialDocumentError::UnsupportedVersion(self.version));
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "ron")]
+    pub fn to_ron(&self) -> Result<String, SpatialDocumentError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(SpatialDocumentError::Ron)
+    }
+
+    #[cfg(feature = "ron")]
+    pub fn from_ron(text: &str) -> Result<Self, SpatialDocumentError> {
+        let document: Self = ron::from_str(text).map_err(SpatialDocumentError::RonParse)?;
+        document.check_version()?;
+        Ok(document)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String, SpatialDocumentError> {
+        serde_json::to_string_pretty(self).map_err(SpatialDocumentError::Json)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(text: &str) -> Result<Self, SpatialDocumentError> {
+        let document: Self = serde_json::from_str(text).map_err(SpatialDocumentError::Json)?;
+        document.check_version()?;
+        Ok(document)
+    }
+}
+
+impl SpatialNode2D {
+    pub fn from_world(world: &World, entity: Entity) -> Option<Self> {
+        let entity_ref = world.get_entity(entity)?;
+        Some(Self {
+            name: entity_ref.get::<Name>().map(|name| name.to_string()),
+            position: *entity_ref.get::<Position2D>()?,
+            rotation: entity_ref.get::<Rotation2D>().copied().unwrap_or_default(),
+            scale: entity_ref.get::<Scale2D>().copied().unwrap_or_default(),
+            draw_order: entity_ref.get::<DrawOrder>().copied().unwrap_or_default(),
+            rotation_propagation: entity_ref
+                .get::<RotationPropagation>()
+                .copied()
+                .unwrap_or_default(),
+            position_propagation: entity_ref
+                .get::<PositionPropagation>()
+                .copied()
+                .unwrap_or_default(),
+            scale_propagation: entity_ref
+                .get::<ScalePropagation>()
+                .copied()
+                .unwrap_or_default(),
+            children: entity_ref
+                .get::<Children>()
+                .into_iter()
+                .flatten()
+                .filter_map(|child| Self::from_world(world, *child))
+                .collect(),
+        })
+    }
+
+    pub fn spawn(&self, world: &mut World) -> Entity {
+        let mut entity = world.spawn(SpatialBundle2D {
+            position: self.position,
+            rotation: self.rotation,
+            scale: self.scale,
+            draw_order: self.draw_order,
+            r_prop: self.rotation_propagation,
+            p_prop: self.position_propagation,
+            s_prop: self.scale_propagation,
+            ..default()
+        });
+        if let Some(name) = &self.name {
+            entity.insert(Name::new(name.clone()));
+        }
+        let entity = entity.id();
+        for child in &self.children {
+            let child = child.spawn(world);
+            world.entity_mut(child).set_parent(entity);
+        }
+        entity
+    }
+}
+
+impl fmt::Display for SpatialDocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "spatial document version {version} is newer than the supported {SPATIAL_DOCUMENT_VERSION}"
+            ),
+            #[cfg(feature = "ron")]
+            Self::Ron(error) => write!(f, "{error}"),
+            #[cfg(feature = "ron")]
+            Self::RonParse(error) => write!(f, "{error}"),
+            #[cfg(feature = "json")]
+            Self::Json(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for SpatialDocumentError {}
+
+mod default {
+    use super::*;
+    impl Default for SpatialDocument2D {
+        fn default() -> Self {
+            Self::new(Vec::new())
+        }
+    }
+}
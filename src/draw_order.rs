@@ -2,6 +2,8 @@ use bevy::prelude::*;
 
 #[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect, Deref, DerefMut)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct DrawOrder(f32);
 
 impl DrawOrder {
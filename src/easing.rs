@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticOut,
+    BounceOut,
+    // Jumps to the end value once the time is up
+    Step,
+}
+
+impl Easing {
+    // Maps linear progress in 0..=1 to eased progress, which may overshoot for Back and Elastic
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadIn => t * t,
+            Self::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::QuadInOut => in_out(t, |t| t * t),
+            Self::CubicIn => t * t * t,
+            Self::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Self::CubicInOut => in_out(t, |t| t * t * t),
+            Self::SineIn => 1.0 - (t * PI * 0.5).cos(),
+            Self::SineOut => (t * PI * 0.5).sin(),
+            Self::SineInOut => -((t * PI).cos() - 1.0) * 0.5,
+            Self::ExpoIn => expo_in(t),
+            Self::ExpoOut => 1.0 - expo_in(1.0 - t),
+            Self::ExpoInOut => in_out(t, expo_in),
+            Self::BackIn => back_in(t),
+            Self::BackOut => 1.0 - back_in(1.0 - t),
+            Self::BackInOut => in_out(t, back_in),
+            Self::ElasticOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+                }
+            }
+            Self::BounceOut => bounce_out(t),
+            Self::Step => {
+                if t < 1.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) * 0.5
+    } else {
+        1.0 - ease_in((1.0 - t) * 2.0) * 0.5
+    }
+}
+
+fn expo_in(t: f32) -> f32 {
+    if t == 0.0 {
+        0.0
+    } else {
+        2f32.powf(10.0 * t - 10.0)
+    }
+}
+
+fn back_in(t: f32) -> f32 {
+    const OVERSHOOT: f32 = 1.70158;
+    (OVERSHOOT + 1.0) * t * t * t - OVERSHOOT * t * t
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
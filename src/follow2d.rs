@@ -0,0 +1,121 @@
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum FollowOffset2D {
+    World(Vec2),
+    // Rotated and scaled with the target, for muzzles and attachment points
+    TargetLocal(Vec2),
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum FollowSmoothing2D {
+    #[default]
+    None,
+    // Exponential catch up, higher rates are snappier
+    Lag {
+        rate: f32,
+    },
+    Spring {
+        stiffness: f32,
+        damping: f32,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, MapEntities)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Follow2D {
+    pub target: Entity,
+    pub offset: FollowOffset2D,
+    pub smoothing: FollowSmoothing2D,
+    pub dead_zone: f32,
+    // Locked axes keep their Position2D value
+    pub lock_axes: BVec2,
+    pub max_distance: f32,
+    velocity: Vec2,
+}
+
+impl Follow2D {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: FollowOffset2D::World(Vec2::ZERO),
+            smoothing: FollowSmoothing2D::None,
+            dead_zone: 0.0,
+            lock_axes: BVec2::FALSE,
+            max_distance: f32::INFINITY,
+            velocity: Vec2::ZERO,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: FollowOffset2D) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_smoothing(mut self, smoothing: FollowSmoothing2D) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    pub fn with_dead_zone(mut self, radius: f32) -> Self {
+        self.dead_zone = radius;
+        self
+    }
+
+    pub fn with_lock_axes(mut self, x: bool, y: bool) -> Self {
+        self.lock_axes = BVec2::new(x, y);
+        self
+    }
+
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    // World space step from `current` towards `goal`
+    pub fn step(&mut self, current: Vec2, goal: Vec2, delta: f32) -> Vec2 {
+        let mut chase = goal;
+        let to_goal = goal - current;
+        if to_goal.length() <= self.dead_zone {
+            chase = current;
+        } else if self.dead_zone > 0.0 {
+            chase -= to_goal.normalize() * self.dead_zone;
+        }
+
+        let next = match self.smoothing {
+            FollowSmoothing2D::None => chase,
+            FollowSmoothing2D::Lag { rate } => current.lerp(chase, 1.0 - (-rate * delta).exp()),
+            FollowSmoothing2D::Spring { stiffness, damping } => {
+                let force = (chase - current) * stiffness - self.velocity * damping;
+                self.velocity += force * delta;
+                current + self.velocity * delta
+            }
+        };
+
+        // Lagging never leaves the follower further behind than max_distance
+        goal + (next - goal).clamp_length_max(self.max_distance)
+    }
+}
+
+impl MapEntities for Follow2D {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.target = entity_mapper.map_entity(self.target);
+    }
+}
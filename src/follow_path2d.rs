@@ -0,0 +1,341 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+const CURVE_SAMPLES: usize = 16;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum PathCurve2D {
+    #[default]
+    Linear,
+    CatmullRom,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum PathLoop2D {
+    #[default]
+    Once,
+    // Closes the path back to the first point
+    Loop,
+    PingPong,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum PathEventKind2D {
+    Waypoint(usize),
+    Looped,
+    Finished,
+}
+
+#[derive(Event, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+pub struct PathEvent2D {
+    pub entity: Entity,
+    pub kind: PathEventKind2D,
+}
+
+// Points are in the same space as Position2D. A negative speed runs the path backwards,
+// starting from the last point.
+#[derive(Clone, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct FollowPath2D {
+    points: Vec<Position2D>,
+    curve: PathCurve2D,
+    mode: PathLoop2D,
+    pub speed: f32,
+    pub face_along: bool,
+    distance: f32,
+    forward: bool,
+    finished: bool,
+    // Arc length table, rebuilt whenever it's found empty. Anything that changes the shape of
+    // the path has to clear it, which is why the points, curve and mode are behind setters.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    samples: Vec<(Vec2, f32)>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    waypoints: Vec<f32>,
+}
+
+impl FollowPath2D {
+    pub fn new(points: impl IntoIterator<Item = Position2D>, speed: f32) -> Self {
+        let mut path = Self {
+            points: points.into_iter().collect(),
+            curve: PathCurve2D::Linear,
+            mode: PathLoop2D::Once,
+            speed,
+            face_along: false,
+            distance: 0.0,
+            forward: true,
+            finished: false,
+            samples: Vec::new(),
+            waypoints: Vec::new(),
+        };
+        path.restart();
+        path
+    }
+
+    pub fn with_curve(mut self, curve: PathCurve2D) -> Self {
+        self.set_curve(curve);
+        self
+    }
+
+    pub fn with_mode(mut self, mode: PathLoop2D) -> Self {
+        self.set_mode(mode);
+        self
+    }
+
+    pub fn facing_along(mut self) -> Self {
+        self.face_along = true;
+        self
+    }
+
+    pub fn points(&self) -> &[Position2D] {
+        &self.points
+    }
+
+    pub fn curve(&self) -> PathCurve2D {
+        self.curve
+    }
+
+    // Keeps the distance travelled, which may land somewhere else on the new curve
+    pub fn set_curve(&mut self, curve: PathCurve2D) {
+        if self.curve != curve {
+            self.curve = curve;
+            self.samples.clear();
+        }
+    }
+
+    pub fn mode(&self) -> PathLoop2D {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: PathLoop2D) {
+        if self.mode != mode {
+            self.mode = mode;
+            self.samples.clear();
+            self.distance = self.distance.min(self.length());
+        }
+    }
+
+    pub fn set_points(&mut self, points: impl IntoIterator<Item = Position2D>) {
+        self.points = points.into_iter().collect();
+        self.samples.clear();
+        self.restart();
+    }
+
+    pub fn restart(&mut self) {
+        self.distance = if self.reversed() { self.length() } else { 0.0 };
+        self.forward = true;
+        self.finished = false;
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn length(&mut self) -> f32 {
+        self.build_samples();
+        self.samples.last().map_or(0.0, |(_, length)| *length)
+    }
+
+    pub fn progress(&mut self) -> f32 {
+        let length = self.length();
+        if length == 0.0 {
+            return 1.0;
+        }
+        self.distance / length
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn reversed(&self) -> bool {
+        self.speed < 0.0
+    }
+
+    // Which way along the path it's heading, ping pong's return leg included
+    fn heading_forward(&self) -> bool {
+        self.forward != self.reversed()
+    }
+
+    fn closed(&self) -> bool {
+        self.mode == PathLoop2D::Loop
+    }
+
+    fn point(&self, index: isize) -> Vec2 {
+        let count = self.points.len() as isize;
+        let index = if self.closed() {
+            index.rem_euclid(count)
+        } else {
+            index.clamp(0, count - 1)
+        };
+        self.points[index as usize].into()
+    }
+
+    fn build_samples(&mut self) {
+        if !self.samples.is_empty() || self.points.is_empty() {
+            return;
+        }
+        let spans = if self.closed() {
+            self.points.len()
+        } else {
+            self.points.len() - 1
+        };
+        let steps = match self.curve {
+            PathCurve2D::Linear => 1,
+            PathCurve2D::CatmullRom => CURVE_SAMPLES,
+        };
+
+        let mut samples = vec![(self.point(0), 0.0)];
+        let mut waypoints = vec![0.0];
+        for span in 0..spans as isize {
+            let [p0, p1, p2, p3] = [span - 1, span, span + 1, span + 2].map(|i| self.point(i));
+            for step in 1..=steps {
+                let t = step as f32 / steps as f32;
+                let point = match self.curve {
+                    PathCurve2D::Linear => p1.lerp(p2, t),
+                    PathCurve2D::CatmullRom => catmull_rom(p0, p1, p2, p3, t),
+                };
+                let length = samples.last().unwrap().1 + samples.last().unwrap().0.distance(point);
+                samples.push((point, length));
+            }
+            waypoints.push(samples.last().unwrap().1);
+        }
+        self.samples = samples;
+        self.waypoints = waypoints;
+    }
+
+    fn waypoint_index(&self, waypoint: usize) -> usize {
+        waypoint % self.points.len()
+    }
+
+    // Moves along the path, reporting every waypoint, loop and finish crossed on the way
+    pub fn advance(&mut self, delta: f32, mut on_event: impl FnMut(PathEventKind2D)) {
+        let length = self.length();
+        if self.finished || length == 0.0 {
+            return;
+        }
+
+        let mut remaining = self.speed.abs() * delta;
+        // Very long steps on short paths could otherwise wrap forever
+        for _ in 0..64 {
+            if remaining <= 0.0 {
+                break;
+            }
+            let forward = self.heading_forward();
+            let (origin, goal) = if forward {
+                (0.0, length)
+            } else {
+                (length, 0.0)
+            };
+            let start = self.distance;
+            let target = if forward {
+                start + remaining
+            } else {
+                start - remaining
+            };
+            let end = target.clamp(0.0, length);
+            // In the order they're passed, so backwards legs count down
+            let count = self.waypoints.len();
+            for step in 0..count {
+                let index = if forward { step } else { count - 1 - step };
+                let waypoint = self.waypoints[index];
+                let crossed = if forward {
+                    waypoint > start && waypoint <= end
+                } else {
+                    waypoint < start && waypoint >= end
+                };
+                if crossed {
+                    on_event(PathEventKind2D::Waypoint(self.waypoint_index(index)));
+                }
+            }
+            remaining = (target - end).abs();
+            self.distance = end;
+            if end != goal {
+                break;
+            }
+
+            match self.mode {
+                PathLoop2D::Once => {
+                    self.finished = true;
+                    on_event(PathEventKind2D::Finished);
+                    break;
+                }
+                PathLoop2D::Loop => {
+                    self.distance = origin;
+                    on_event(PathEventKind2D::Looped);
+                }
+                PathLoop2D::PingPong => {
+                    self.forward = !self.forward;
+                    if self.forward {
+                        on_event(PathEventKind2D::Looped);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn position(&mut self) -> Position2D {
+        self.sample().0.into()
+    }
+
+    // Direction of travel
+    pub fn tangent(&mut self) -> Vec2 {
+        let tangent = self.sample().1;
+        if self.heading_forward() {
+            tangent
+        } else {
+            -tangent
+        }
+    }
+
+    fn sample(&mut self) -> (Vec2, Vec2) {
+        self.build_samples();
+        match self.samples.len() {
+            0 => return (Vec2::ZERO, Vec2::X),
+            1 => return (self.samples[0].0, Vec2::X),
+            _ => {}
+        }
+        let next = self
+            .samples
+            .partition_point(|(_, length)| *length < self.distance)
+            .clamp(1, self.samples.len() - 1);
+        let (from, from_length) = self.samples[next - 1];
+        let (to, to_length) = self.samples[next];
+        let span = to_length - from_length;
+        let t = if span > 0.0 {
+            (self.distance - from_length) / span
+        } else {
+            0.0
+        };
+        (from.lerp(to, t), (to - from).normalize_or_zero())
+    }
+}
+
+fn catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * ((2.0 * p1)
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+mod default {
+    use super::*;
+    impl Default for FollowPath2D {
+        fn default() -> Self {
+            Self::new(Vec::new(), 0.0)
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::prelude::*;
+use crate::propagation_systems::local_transform;
+use bevy::{ecs::system::SystemParam, math::Affine2, prelude::*};
+
+// World space as propagate_spatial2d will produce it this frame, without waiting for
+// GlobalTransform. Snapping and projection are ignored, so this is ground space.
+#[derive(SystemParam)]
+pub struct GlobalSpatial2D<'w, 's> {
+    spatial: Query<
+        'w,
+        's,
+        (
+            &'static Position2D,
+            &'static Rotation2D,
+            &'static Scale2D,
+            &'static RotationPropagation,
+            &'static PositionPropagation,
+            &'static ScalePropagation,
+            Option<&'static Parent>,
+        ),
+    >,
+}
+
+impl<'w, 's> GlobalSpatial2D<'w, 's> {
+    pub fn local_affine(&self, entity: Entity) -> Option<Affine2> {
+        self.local_affine_under(entity, self.parent_world_affine(entity))
+    }
+
+    // Maps the entity's Position2D space to world space. That's the parent's world affine,
+    // or identity for roots and absolute positions, which are already world values.
+    pub fn parent_affine(&self, entity: Entity) -> Affine2 {
+        match self.spatial.get(entity) {
+            Ok((_, _, _, _, PositionPropagation::Absolute, _, _)) => Affine2::IDENTITY,
+            _ => self
+                .parent_world_affine(entity)
+                .unwrap_or(Affine2::IDENTITY),
+        }
+    }
+
+    pub fn world_affine(&self, entity: Entity) -> Option<Affine2> {
+        let parent = self.parent_world_affine(entity);
+        Some(parent.unwrap_or(Affine2::IDENTITY) * self.local_affine_under(entity, parent)?)
+    }
+
+    pub fn world_position(&self, entity: Entity) -> Option<Position2D> {
+        Some(self.world_affine(entity)?.translation.into())
+    }
+
+    pub fn world_rotation(&self, entity: Entity) -> Option<Rotation2D> {
+        let x_axis = self.world_affine(entity)?.matrix2.x_axis;
+        Some(Rotation2D::from_f32_radians(x_axis.to_angle()))
+    }
+
+    // The parent's world rotation whatever the position propagation, relative rotations add to it
+    pub fn parent_rotation(&self, entity: Entity) -> Rotation2D {
+        let parent = self
+            .parent_world_affine(entity)
+            .unwrap_or(Affine2::IDENTITY);
+        Rotation2D::from_f32_radians(parent.matrix2.x_axis.to_angle())
+    }
+
+    // Converts a world space point into this entity's Position2D space, see parent_affine
+    pub fn world_to_parent(&self, entity: Entity, world: Position2D) -> Position2D {
+        self.parent_affine(entity)
+            .inverse()
+            .transform_point2(world.into())
+            .into()
+    }
+
+    pub fn world_to_parent_vector(&self, entity: Entity, world: Vec2) -> Vec2 {
+        self.parent_affine(entity)
+            .inverse()
+            .transform_vector2(world)
+    }
+
+    // Maps a vector in the given velocity frame into this entity's Position2D space
+    pub fn frame_to_parent(&self, entity: Entity, frame: VelocityFrame2D) -> Mat2 {
+        let parent_from_world = || self.parent_affine(entity).inverse().matrix2;
+        match frame {
+            VelocityFrame2D::Parent => Mat2::IDENTITY,
+            VelocityFrame2D::World => parent_from_world(),
+            VelocityFrame2D::Local => self
+                .world_rotation(entity)
+                .map_or(Mat2::IDENTITY, |rotation| {
+                    parent_from_world() * Mat2::from_angle(rotation.radians().to_f32())
+                }),
+        }
+    }
+
+    // Ancestors without spatial components end the chain, like they do for GlobalTransform
+    fn parent_world_affine(&self, entity: Entity) -> Option<Affine2> {
+        self.world_affine(self.parent(entity)?)
+    }
+
+    fn local_affine_under(&self, entity: Entity, parent: Option<Affine2>) -> Option<Affine2> {
+        let (position, rotation, scale, r_prop, p_prop, s_prop, _) =
+            self.spatial.get(entity).ok()?;
+        let transform = local_transform(
+            (position, rotation, scale, &DrawOrder::default()),
+            (r_prop, p_prop, s_prop),
+            parent,
+        );
+        Some(Affine2::from_scale_angle_translation(
+            transform.scale.truncate(),
+            Rotation2D::from(transform.rotation).radians().to_f32(),
+            transform.translation.truncate(),
+        ))
+    }
+
+    fn parent(&self, entity: Entity) -> Option<Entity> {
+        self.spatial.get(entity).ok()?.6.map(Parent::get)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Debug, Resource, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Resource, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Grid2D {
+    pub cell_size: Vec2,
+    pub origin: Position2D,
+    pub rotation: Rotation2D,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum CellAnchor {
+    #[default]
+    Center,
+    Corner,
+}
+
+// Snaps to the parent's Grid2D, only root entities fall back to the Grid2D resource
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct SnapToGrid {
+    pub anchor: CellAnchor,
+}
+
+impl Grid2D {
+    pub fn new(cell_size: Vec2) -> Self {
+        assert!(cell_size.x > 0.0 && cell_size.y > 0.0);
+        Self {
+            cell_size,
+            origin: Position2D::ZERO,
+            rotation: Rotation2D::default(),
+        }
+    }
+
+    pub fn square(cell_size: f32) -> Self {
+        Self::new(Vec2::splat(cell_size))
+    }
+
+    pub fn with_origin(mut self, origin: Position2D) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn with_rotation(mut self, rotation: Rotation2D) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    fn world_to_grid(&self, position: Position2D) -> Vec2 {
+        Vec2::from((position - self.origin).rotate_radians(-self.rotation.radians()))
+            / self.cell_size
+    }
+
+    fn grid_to_world(&self, grid: Vec2) -> Position2D {
+        Position2D::from(grid * self.cell_size).rotate_radians(self.rotation.radians())
+            + self.origin
+    }
+
+    pub fn world_to_cell(&self, position: Position2D) -> GridCoord {
+        self.world_to_grid(position).floor().as_ivec2().into()
+    }
+
+    pub fn cell_to_world(&self, cell: GridCoord, anchor: CellAnchor) -> Position2D {
+        let corner = IVec2::from(cell).as_vec2();
+        match anchor {
+            CellAnchor::Center => self.grid_to_world(corner + 0.5),
+            CellAnchor::Corner => self.grid_to_world(corner),
+        }
+    }
+
+    pub fn cell_center(&self, cell: GridCoord) -> Position2D {
+        self.cell_to_world(cell, CellAnchor::Center)
+    }
+
+    pub fn cell_corner(&self, cell: GridCoord) -> Position2D {
+        self.cell_to_world(cell, CellAnchor::Corner)
+    }
+
+    pub fn snap(&self, position: Position2D, anchor: CellAnchor) -> Position2D {
+        match anchor {
+            CellAnchor::Center => self.cell_center(self.world_to_cell(position)),
+            CellAnchor::Corner => self.grid_to_world(self.world_to_grid(position).round()),
+        }
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for Grid2D {
+        fn default() -> Self {
+            Self::new(Vec2::ONE)
+        }
+    }
+}
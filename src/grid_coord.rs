@@ -0,0 +1,176 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct GridCoord {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl GridCoord {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub const ZERO: Self = Self { x: 0, y: 0 };
+
+    pub fn neighbor(self, direction: impl Into<CompassRose>) -> Self {
+        self + GridCoord::from(direction.into())
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = (Compass, GridCoord)> {
+        Compass::ALL
+            .into_iter()
+            .map(move |direction| (direction, self.neighbor(direction)))
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = (CompassRose, GridCoord)> {
+        CompassRose::ALL
+            .into_iter()
+            .map(move |direction| (direction, self.neighbor(direction)))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+mod from {
+    use super::GridCoord;
+    use crate::prelude::{Compass, CompassRose};
+    use bevy::math::IVec2;
+
+    impl From<IVec2> for GridCoord {
+        fn from(value: IVec2) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<&IVec2> for GridCoord {
+        fn from(value: &IVec2) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<Compass> for GridCoord {
+        fn from(compass: Compass) -> Self {
+            Self::from(CompassRose::from(compass))
+        }
+    }
+
+    impl From<&Compass> for GridCoord {
+        fn from(compass: &Compass) -> Self {
+            Self::from(*compass)
+        }
+    }
+
+    impl From<CompassRose> for GridCoord {
+        fn from(compass_rose: CompassRose) -> Self {
+            match compass_rose {
+                CompassRose::N => Self::new(0, 1),
+                CompassRose::NE => Self::new(1, 1),
+                CompassRose::E => Self::new(1, 0),
+                CompassRose::SE => Self::new(1, -1),
+                CompassRose::S => Self::new(0, -1),
+                CompassRose::SW => Self::new(-1, -1),
+                CompassRose::W => Self::new(-1, 0),
+                CompassRose::NW => Self::new(-1, 1),
+            }
+        }
+    }
+
+    impl From<&CompassRose> for GridCoord {
+        fn from(compass_rose: &CompassRose) -> Self {
+            Self::from(*compass_rose)
+        }
+    }
+}
+
+mod into {
+    use super::GridCoord;
+    use bevy::math::IVec2;
+
+    impl From<GridCoord> for IVec2 {
+        fn from(value: GridCoord) -> Self {
+            IVec2::new(value.x, value.y)
+        }
+    }
+
+    impl From<&GridCoord> for IVec2 {
+        fn from(value: &GridCoord) -> Self {
+            IVec2::new(value.x, value.y)
+        }
+    }
+}
+
+mod operators {
+    use super::GridCoord;
+    use bevy::math::IVec2;
+    use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+    impl Add<GridCoord> for GridCoord {
+        type Output = GridCoord;
+        fn add(self, rhs: GridCoord) -> Self::Output {
+            GridCoord::new(self.x + rhs.x, self.y + rhs.y)
+        }
+    }
+
+    impl Add<IVec2> for GridCoord {
+        type Output = GridCoord;
+        fn add(self, rhs: IVec2) -> Self::Output {
+            GridCoord::new(self.x + rhs.x, self.y + rhs.y)
+        }
+    }
+
+    impl AddAssign<GridCoord> for GridCoord {
+        fn add_assign(&mut self, rhs: GridCoord) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl AddAssign<IVec2> for GridCoord {
+        fn add_assign(&mut self, rhs: IVec2) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl Sub<GridCoord> for GridCoord {
+        type Output = GridCoord;
+        fn sub(self, rhs: GridCoord) -> Self::Output {
+            GridCoord::new(self.x - rhs.x, self.y - rhs.y)
+        }
+    }
+
+    impl Sub<IVec2> for GridCoord {
+        type Output = GridCoord;
+        fn sub(self, rhs: IVec2) -> Self::Output {
+            GridCoord::new(self.x - rhs.x, self.y - rhs.y)
+        }
+    }
+
+    impl SubAssign<GridCoord> for GridCoord {
+        fn sub_assign(&mut self, rhs: GridCoord) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl SubAssign<IVec2> for GridCoord {
+        fn sub_assign(&mut self, rhs: IVec2) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl Neg for GridCoord {
+        type Output = GridCoord;
+        fn neg(self) -> Self::Output {
+            GridCoord::new(-self.x, -self.y)
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use bevy::prelude::*;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct HexCoord {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl HexCoord {
+    pub fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Self {
+        assert!(q + r + s == 0);
+        Self { q, r }
+    }
+
+    pub const ZERO: Self = Self { q: 0, r: 0 };
+
+    // Axial offsets in counter-clockwise order, starting from +q
+    pub const OFFSETS: [Self; 6] = [
+        Self { q: 1, r: 0 },
+        Self { q: 0, r: 1 },
+        Self { q: -1, r: 1 },
+        Self { q: -1, r: 0 },
+        Self { q: 0, r: -1 },
+        Self { q: 1, r: -1 },
+    ];
+
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    pub fn to_cube(self) -> IVec3 {
+        IVec3::new(self.q, self.r, self.s())
+    }
+
+    // Rounds fractional axial coordinates to the hex that contains them
+    pub fn round(fractional: Vec2) -> Self {
+        let s = -fractional.x - fractional.y;
+        let mut q = fractional.x.round();
+        let mut r = fractional.y.round();
+        let rounded_s = s.round();
+        let q_diff = (q - fractional.x).abs();
+        let r_diff = (r - fractional.y).abs();
+        let s_diff = (rounded_s - s).abs();
+        if q_diff > r_diff && q_diff > s_diff {
+            q = -r - rounded_s;
+        } else if r_diff > s_diff {
+            r = -q - rounded_s;
+        }
+        Self::new(q as i32, r as i32)
+    }
+
+    pub fn length(self) -> u32 {
+        (self.q.unsigned_abs() + self.r.unsigned_abs() + self.s().unsigned_abs()) / 2
+    }
+
+    pub fn distance(self, other: Self) -> u32 {
+        (self - other).length()
+    }
+
+    pub fn neighbor(self, direction: impl Into<HexCoord>) -> Self {
+        self + direction.into()
+    }
+
+    pub fn neighbors(self) -> [Self; 6] {
+        Self::OFFSETS.map(|offset| self + offset)
+    }
+
+    pub fn ring(self, radius: u32) -> Vec<Self> {
+        if radius == 0 {
+            return vec![self];
+        }
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut current = self + Self::OFFSETS[4] * radius as i32;
+        for offset in Self::OFFSETS {
+            for _ in 0..radius {
+                ring.push(current);
+                current += offset;
+            }
+        }
+        ring
+    }
+
+    pub fn spiral(self, radius: u32) -> Vec<Self> {
+        (0..=radius).flat_map(|ring| self.ring(ring)).collect()
+    }
+
+    pub fn line_to(self, other: Self) -> Vec<Self> {
+        let steps = self.distance(other);
+        let start = Vec2::new(self.q as f32, self.r as f32);
+        let end = Vec2::new(other.q as f32, other.r as f32);
+        // Nudge off the exact edges so ties round consistently
+        let nudge = Vec2::new(1e-6, 2e-6);
+        (0..=steps)
+            .map(|step| {
+                let t = if steps == 0 {
+                    0.0
+                } else {
+                    step as f32 / steps as f32
+                };
+                Self::round(start.lerp(end, t) + nudge)
+            })
+            .collect()
+    }
+}
+
+mod from {
+    use super::HexCoord;
+    use bevy::math::IVec2;
+
+    impl From<IVec2> for HexCoord {
+        fn from(value: IVec2) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<HexCoord> for IVec2 {
+        fn from(value: HexCoord) -> Self {
+            IVec2::new(value.q, value.r)
+        }
+    }
+}
+
+mod operators {
+    use super::HexCoord;
+    use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+    impl Add<HexCoord> for HexCoord {
+        type Output = HexCoord;
+        fn add(self, rhs: HexCoord) -> Self::Output {
+            HexCoord::new(self.q + rhs.q, self.r + rhs.r)
+        }
+    }
+
+    impl AddAssign<HexCoord> for HexCoord {
+        fn add_assign(&mut self, rhs: HexCoord) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl Sub<HexCoord> for HexCoord {
+        type Output = HexCoord;
+        fn sub(self, rhs: HexCoord) -> Self::Output {
+            HexCoord::new(self.q - rhs.q, self.r - rhs.r)
+        }
+    }
+
+    impl SubAssign<HexCoord> for HexCoord {
+        fn sub_assign(&mut self, rhs: HexCoord) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl Mul<i32> for HexCoord {
+        type Output = HexCoord;
+        fn mul(self, rhs: i32) -> Self::Output {
+            HexCoord::new(self.q * rhs, self.r * rhs)
+        }
+    }
+
+    impl Neg for HexCoord {
+        type Output = HexCoord;
+        fn neg(self) -> Self::Output {
+            HexCoord::new(-self.q, -self.r)
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum PointyHexDirection {
+    #[default]
+    E,
+    NE,
+    NW,
+    W,
+    SW,
+    SE,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum FlatHexDirection {
+    #[default]
+    N,
+    NE,
+    SE,
+    S,
+    SW,
+    NW,
+}
+
+// Both enums follow HexCoord::OFFSETS, so the index is also the axial direction
+impl PointyHexDirection {
+    pub const ALL: [Self; 6] = [Self::E, Self::NE, Self::NW, Self::W, Self::SW, Self::SE];
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|d| d == self).unwrap()
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self.index() + 1) % 6]
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self.index() + 5) % 6]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 3) % 6]
+    }
+}
+
+impl FlatHexDirection {
+    pub const ALL: [Self; 6] = [Self::NE, Self::N, Self::NW, Self::SW, Self::S, Self::SE];
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|d| d == self).unwrap()
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self.index() + 1) % 6]
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self.index() + 5) % 6]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 3) % 6]
+    }
+}
+
+fn sector(degrees: f32, start: f32) -> usize {
+    let normalized = (((degrees - start) % 360.0) + 360.0) % 360.0;
+    ((normalized / 60.0).round() as usize) % 6
+}
+
+mod from {
+    use super::{sector, FlatHexDirection, PointyHexDirection};
+    use crate::{
+        components::Rotation2D,
+        math::{Degrees, Radians},
+    };
+
+    impl From<Degrees> for PointyHexDirection {
+        fn from(degrees: Degrees) -> Self {
+            Self::ALL[sector(degrees.to_f32(), 0.0)]
+        }
+    }
+
+    impl From<&Degrees> for PointyHexDirection {
+        fn from(degrees: &Degrees) -> Self {
+            Self::from(*degrees)
+        }
+    }
+
+    impl From<Radians> for PointyHexDirection {
+        fn from(radians: Radians) -> Self {
+            Self::ALL[sector(radians.to_degrees_f32(), 0.0)]
+        }
+    }
+
+    impl From<&Radians> for PointyHexDirection {
+        fn from(radians: &Radians) -> Self {
+            Self::from(*radians)
+        }
+    }
+
+    impl From<Rotation2D> for PointyHexDirection {
+        fn from(rotation: Rotation2D) -> Self {
+            Self::from(rotation.degrees())
+        }
+    }
+
+    impl From<&Rotation2D> for PointyHexDirection {
+        fn from(rotation: &Rotation2D) -> Self {
+            Self::from(*rotation)
+        }
+    }
+
+    impl From<FlatHexDirection> for PointyHexDirection {
+        fn from(direction: FlatHexDirection) -> Self {
+            Self::ALL[direction.index()]
+        }
+    }
+
+    impl From<Degrees> for FlatHexDirection {
+        fn from(degrees: Degrees) -> Self {
+            Self::ALL[sector(degrees.to_f32(), 30.0)]
+        }
+    }
+
+    impl From<&Degrees> for FlatHexDirection {
+        fn from(degrees: &Degrees) -> Self {
+            Self::from(*degrees)
+        }
+    }
+
+    impl From<Radians> for FlatHexDirection {
+        fn from(radians: Radians) -> Self {
+            Self::ALL[sector(radians.to_degrees_f32(), 30.0)]
+        }
+    }
+
+    impl From<&Radians> for FlatHexDirection {
+        fn from(radians: &Radians) -> Self {
+            Self::from(*radians)
+        }
+    }
+
+    impl From<Rotation2D> for FlatHexDirection {
+        fn from(rotation: Rotation2D) -> Self {
+            Self::from(rotation.degrees())
+        }
+    }
+
+    impl From<&Rotation2D> for FlatHexDirection {
+        fn from(rotation: &Rotation2D) -> Self {
+            Self::from(*rotation)
+        }
+    }
+
+    impl From<PointyHexDirection> for FlatHexDirection {
+        fn from(direction: PointyHexDirection) -> Self {
+            Self::ALL[direction.index()]
+        }
+    }
+}
+
+mod into {
+    use super::{FlatHexDirection, PointyHexDirection};
+    use crate::{
+        components::{HexCoord, Rotation2D},
+        math::{Degrees, Radians},
+    };
+    use bevy::math::Vec2;
+
+    impl From<PointyHexDirection> for Degrees {
+        fn from(direction: PointyHexDirection) -> Self {
+            Degrees::from_f32(60.0 * direction.index() as f32)
+        }
+    }
+
+    impl From<PointyHexDirection> for Radians {
+        fn from(direction: PointyHexDirection) -> Self {
+            Radians::from_f32(Degrees::from(direction).to_radians_f32())
+        }
+    }
+
+    impl From<PointyHexDirection> for Rotation2D {
+        fn from(direction: PointyHexDirection) -> Self {
+            Rotation2D::from(Degrees::from(direction))
+        }
+    }
+
+    impl From<PointyHexDirection> for Vec2 {
+        fn from(direction: PointyHexDirection) -> Self {
+            Vec2::from(Radians::from(direction))
+        }
+    }
+
+    impl From<PointyHexDirection> for HexCoord {
+        fn from(direction: PointyHexDirection) -> Self {
+            HexCoord::OFFSETS[direction.index()]
+        }
+    }
+
+    impl From<FlatHexDirection> for Degrees {
+        fn from(direction: FlatHexDirection) -> Self {
+            Degrees::from_f32(30.0 + 60.0 * direction.index() as f32)
+        }
+    }
+
+    impl From<FlatHexDirection> for Radians {
+        fn from(direction: FlatHexDirection) -> Self {
+            Radians::from_f32(Degrees::from(direction).to_radians_f32())
+        }
+    }
+
+    impl From<FlatHexDirection> for Rotation2D {
+        fn from(direction: FlatHexDirection) -> Self {
+            Rotation2D::from(Degrees::from(direction))
+        }
+    }
+
+    impl From<FlatHexDirection> for Vec2 {
+        fn from(direction: FlatHexDirection) -> Self {
+            Vec2::from(Radians::from(direction))
+        }
+    }
+
+    impl From<FlatHexDirection> for HexCoord {
+        fn from(direction: FlatHexDirection) -> Self {
+            HexCoord::OFFSETS[direction.index()]
+        }
+    }
+}
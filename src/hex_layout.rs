@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+const SQRT_3: f32 = 1.732_050_8;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum HexOrientation {
+    #[default]
+    PointyTop,
+    FlatTop,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Resource, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Resource, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct HexLayout {
+    pub orientation: HexOrientation,
+    pub size: Vec2,
+    pub origin: Position2D,
+}
+
+impl HexOrientation {
+    // Columns map axial (q, r) onto unit-size world space
+    fn forward(&self) -> Mat2 {
+        match self {
+            Self::PointyTop => {
+                Mat2::from_cols(Vec2::new(SQRT_3, 0.0), Vec2::new(SQRT_3 / 2.0, 1.5))
+            }
+            Self::FlatTop => Mat2::from_cols(Vec2::new(1.5, SQRT_3 / 2.0), Vec2::new(0.0, SQRT_3)),
+        }
+    }
+
+    pub fn start_angle(&self) -> Radians {
+        match self {
+            Self::PointyTop => Radians::from_f32(std::f32::consts::FRAC_PI_6),
+            Self::FlatTop => Radians::ZERO,
+        }
+    }
+}
+
+impl HexLayout {
+    pub fn new(orientation: HexOrientation, size: Vec2) -> Self {
+        assert!(size.x > 0.0 && size.y > 0.0);
+        Self {
+            orientation,
+            size,
+            origin: Position2D::ZERO,
+        }
+    }
+
+    pub fn pointy(size: f32) -> Self {
+        Self::new(HexOrientation::PointyTop, Vec2::splat(size))
+    }
+
+    pub fn flat(size: f32) -> Self {
+        Self::new(HexOrientation::FlatTop, Vec2::splat(size))
+    }
+
+    pub fn with_origin(mut self, origin: Position2D) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn hex_to_world(&self, hex: HexCoord) -> Position2D {
+        let axial = Vec2::new(hex.q as f32, hex.r as f32);
+        Position2D::from(self.orientation.forward() * axial * self.size) + self.origin
+    }
+
+    pub fn world_to_fractional(&self, position: Position2D) -> Vec2 {
+        let local = Vec2::from(position - self.origin) / self.size;
+        self.orientation.forward().inverse() * local
+    }
+
+    pub fn world_to_hex(&self, position: Position2D) -> HexCoord {
+        HexCoord::round(self.world_to_fractional(position))
+    }
+
+    pub fn corners(&self, hex: HexCoord) -> [Position2D; 6] {
+        let center = self.hex_to_world(hex);
+        let start = self.orientation.start_angle().to_f32();
+        std::array::from_fn(|corner| {
+            let angle = start + std::f32::consts::FRAC_PI_3 * corner as f32;
+            center + Vec2::from_angle(angle) * self.size
+        })
+    }
+
+    pub fn snap(&self, position: Position2D) -> Position2D {
+        self.hex_to_world(self.world_to_hex(position))
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for HexLayout {
+        fn default() -> Self {
+            Self::pointy(1.0)
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn update_spatial_index2d<I: SpatialIndex2D>(
+    mut index: ResMut<I>,
+    query: Query<
+        (Entity, &GlobalTransform, Option<&WorldBounds2D>),
+        (
+            With<Position2D>,
+            Or<(Changed<GlobalTransform>, Changed<WorldBounds2D>)>,
+        ),
+    >,
+    all: Query<(Entity, &GlobalTransform, Option<&WorldBounds2D>), With<Position2D>>,
+    mut removed: RemovedComponents<Position2D>,
+) {
+    // A fresh or cleared index has never seen the entities that already stopped moving.
+    // Replacing an existing index only marks it changed, not added.
+    let rebuild = index.is_added() || (index.is_changed() && index.is_empty());
+    for entity in removed.read() {
+        index.remove(entity);
+    }
+    let mut insert =
+        |(entity, transform, world_bounds): (Entity, &GlobalTransform, Option<&WorldBounds2D>)| {
+            match world_bounds {
+                Some(world_bounds) => index.insert(entity, world_bounds.aabb),
+                None => index.insert_point(entity, transform.translation().truncate().into()),
+            }
+        };
+    if rebuild {
+        all.iter().for_each(&mut insert);
+    } else {
+        query.iter().for_each(&mut insert);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct KinematicBody2D {
+    pub mass: f32,
+    pub inertia: f32,
+    pub gravity_scale: f32,
+}
+
+// Expressed in the same frame as the entity's Velocity2D
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Acceleration2D {
+    pub x: f32,
+    pub y: f32,
+}
+
+// Accumulated until the next kinematic step, then cleared
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Forces2D {
+    pub force: Vec2,
+    pub torque: f32,
+    pub impulse: Vec2,
+    pub angular_impulse: f32,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Resource, Reflect, Deref, DerefMut)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Gravity2D(pub Vec2);
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Resource, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum Integrator2D {
+    #[default]
+    SemiImplicitEuler,
+    VelocityVerlet,
+}
+
+#[derive(Bundle, Default)]
+pub struct KinematicBundle2D {
+    pub body: KinematicBody2D,
+    pub velocity: Velocity2D,
+    pub angular_velocity: AngularVelocity2D,
+    pub acceleration: Acceleration2D,
+    pub forces: Forces2D,
+}
+
+impl KinematicBody2D {
+    pub fn new(mass: f32) -> Self {
+        assert!(mass > 0.0);
+        Self { mass, ..default() }
+    }
+
+    pub fn with_inertia(mut self, inertia: f32) -> Self {
+        assert!(inertia > 0.0);
+        self.inertia = inertia;
+        self
+    }
+
+    pub fn with_gravity_scale(mut self, gravity_scale: f32) -> Self {
+        self.gravity_scale = gravity_scale;
+        self
+    }
+
+    pub(crate) fn acceleration(
+        &self,
+        gravity: Vec2,
+        acceleration: Option<&Acceleration2D>,
+        forces: Option<&Forces2D>,
+    ) -> (Vec2, f32) {
+        let mut linear = gravity + acceleration.map_or(Vec2::ZERO, Vec2::from);
+        let mut angular = 0.0;
+        if let Some(forces) = forces {
+            linear += forces.force / self.mass;
+            angular += forces.torque / self.inertia;
+        }
+        (linear, angular)
+    }
+}
+
+impl Acceleration2D {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self::from_f32(x, y)
+    }
+
+    pub fn from_f32(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub const ZERO: Self = Self { x: 0., y: 0. };
+}
+
+impl Forces2D {
+    pub fn apply_force(&mut self, force: Vec2) {
+        self.force += force;
+    }
+
+    pub fn apply_torque(&mut self, torque: f32) {
+        self.torque += torque;
+    }
+
+    pub fn apply_impulse(&mut self, impulse: Vec2) {
+        self.impulse += impulse;
+    }
+
+    pub fn apply_angular_impulse(&mut self, impulse: f32) {
+        self.angular_impulse += impulse;
+    }
+}
+
+impl Gravity2D {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self(Vec2::new(x, y))
+    }
+
+    pub const ZERO: Self = Self(Vec2::ZERO);
+    pub const EARTH: Self = Self(Vec2::new(0.0, -9.81));
+}
+
+mod default {
+    use super::*;
+    impl Default for KinematicBody2D {
+        fn default() -> Self {
+            Self {
+                mass: 1.0,
+                inertia: 1.0,
+                gravity_scale: 1.0,
+            }
+        }
+    }
+}
+
+mod from {
+    use super::*;
+
+    impl From<Vec2> for Acceleration2D {
+        fn from(value: Vec2) -> Self {
+            Self::from_f32(value.x, value.y)
+        }
+    }
+}
+
+mod into {
+    use super::*;
+
+    impl From<Acceleration2D> for Vec2 {
+        fn from(acceleration: Acceleration2D) -> Self {
+            Vec2::new(acceleration.x, acceleration.y)
+        }
+    }
+
+    impl From<&Acceleration2D> for Vec2 {
+        fn from(acceleration: &Acceleration2D) -> Self {
+            Vec2::new(acceleration.x, acceleration.y)
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+
+pub fn integrate_velocity2d(
+    time: Res<Time>,
+    frames: Query<(Entity, &VelocityFrame2D), With<Velocity2D>>,
+    mut set: ParamSet<(
+        GlobalSpatial2D,
+        Query<(
+            Entity,
+            &mut Position2D,
+            &mut Velocity2D,
+            Option<&Damping2D>,
+            Option<&MaxSpeed2D>,
+        )>,
+    )>,
+    mut to_parent: Local<HashMap<Entity, Mat2>>,
+) {
+    let delta = time.delta_seconds();
+    if delta == 0.0 {
+        return;
+    }
+
+    // Resolve every non parent frame against this frame's hierarchy before anything moves
+    to_parent.clear();
+    let spatial = set.p0();
+    for (entity, frame) in frames.iter() {
+        if *frame != VelocityFrame2D::Parent {
+            to_parent.insert(entity, spatial.frame_to_parent(entity, *frame));
+        }
+    }
+
+    set.p1()
+        .par_iter_mut()
+        .for_each(|(entity, mut position, mut velocity, damping, max_speed)| {
+            let mut linear = Vec2::from(*velocity);
+            if let Some(damping) = damping {
+                linear *= (-damping.linear * delta).exp();
+            }
+            if let Some(max_speed) = max_speed {
+                linear = linear.clamp_length_max(max_speed.linear);
+            }
+            if linear != Vec2::from(*velocity) {
+                *velocity = linear.into();
+            }
+
+            let step = to_parent
+                .get(&entity)
+                .map_or(linear, |matrix| *matrix * linear)
+                * delta;
+            if step != Vec2::ZERO {
+                *position = (Vec2::from(*position) + step).into();
+            }
+        });
+}
+
+// Kick half of velocity Verlet, or the whole velocity update for semi-implicit Euler.
+// Position is then stepped by integrate_velocity2d and finish_forces2d closes the step.
+pub fn apply_forces2d(
+    time: Res<Time>,
+    integrator: Res<Integrator2D>,
+    gravity: Res<Gravity2D>,
+    bodies: Query<(Entity, &KinematicBody2D, Option<&VelocityFrame2D>)>,
+    mut set: ParamSet<(
+        GlobalSpatial2D,
+        Query<(
+            Entity,
+            &KinematicBody2D,
+            &mut Velocity2D,
+            Option<&mut AngularVelocity2D>,
+            Option<&Acceleration2D>,
+            Option<&Forces2D>,
+        )>,
+    )>,
+    mut frame_gravity: Local<HashMap<Entity, Vec2>>,
+) {
+    let delta = time.delta_seconds();
+    if delta == 0.0 {
+        return;
+    }
+    let fraction = match *integrator {
+        Integrator2D::SemiImplicitEuler => 1.0,
+        Integrator2D::VelocityVerlet => 0.5,
+    };
+
+    resolve_gravity(&gravity, &bodies, &set.p0(), &mut frame_gravity);
+
+    set.p1().par_iter_mut().for_each(
+        |(entity, body, mut velocity, angular_velocity, acceleration, forces)| {
+            let gravity = frame_gravity.get(&entity).copied().unwrap_or_default();
+            let (linear, angular) = body.acceleration(gravity, acceleration, forces);
+            let (impulse, angular_impulse) = forces.map_or((Vec2::ZERO, 0.0), |forces| {
+                (
+                    forces.impulse / body.mass,
+                    forces.angular_impulse / body.inertia,
+                )
+            });
+
+            let kick = linear * delta * fraction + impulse;
+            if kick != Vec2::ZERO {
+                *velocity = (Vec2::from(*velocity) + kick).into();
+            }
+            let angular_kick = angular * delta * fraction + angular_impulse;
+            if let (Some(mut angular_velocity), true) = (angular_velocity, angular_kick != 0.0) {
+                angular_velocity.0 += angular_kick;
+            }
+        },
+    );
+}
+
+pub fn finish_forces2d(
+    time: Res<Time>,
+    integrator: Res<Integrator2D>,
+    gravity: Res<Gravity2D>,
+    bodies: Query<(Entity, &KinematicBody2D, Option<&VelocityFrame2D>)>,
+    mut set: ParamSet<(
+        GlobalSpatial2D,
+        Query<(
+            Entity,
+            &KinematicBody2D,
+            &mut Velocity2D,
+            Option<&mut AngularVelocity2D>,
+            Option<&Acceleration2D>,
+            Option<&mut Forces2D>,
+        )>,
+    )>,
+    mut frame_gravity: Local<HashMap<Entity, Vec2>>,
+) {
+    let delta = time.delta_seconds();
+    if delta == 0.0 {
+        return;
+    }
+    let verlet = *integrator == Integrator2D::VelocityVerlet;
+    if verlet {
+        resolve_gravity(&gravity, &bodies, &set.p0(), &mut frame_gravity);
+    }
+
+    set.p1().par_iter_mut().for_each(
+        |(entity, body, mut velocity, angular_velocity, acceleration, forces)| {
+            if verlet {
+                let gravity = frame_gravity.get(&entity).copied().unwrap_or_default();
+                let (linear, angular) = body.acceleration(gravity, acceleration, forces.as_deref());
+                let kick = linear * delta * 0.5;
+                if kick != Vec2::ZERO {
+                    *velocity = (Vec2::from(*velocity) + kick).into();
+                }
+                if let (Some(mut angular_velocity), true) = (angular_velocity, angular != 0.0) {
+                    angular_velocity.0 += angular * delta * 0.5;
+                }
+            }
+
+            // Accumulators only live for a single step
+            if let Some(mut forces) = forces {
+                forces.set_if_neq(Forces2D::default());
+            }
+        },
+    );
+}
+
+// Gravity is world space, bodies need it in the frame their Velocity2D is expressed in
+fn resolve_gravity(
+    gravity: &Gravity2D,
+    bodies: &Query<(Entity, &KinematicBody2D, Option<&VelocityFrame2D>)>,
+    spatial: &GlobalSpatial2D,
+    frame_gravity: &mut HashMap<Entity, Vec2>,
+) {
+    frame_gravity.clear();
+    if **gravity == Vec2::ZERO {
+        return;
+    }
+    for (entity, body, frame) in bodies.iter() {
+        if body.gravity_scale == 0.0 {
+            continue;
+        }
+        let frame = frame.copied().unwrap_or_default();
+        let world_to_frame = spatial.frame_to_parent(entity, frame).inverse()
+            * spatial.parent_affine(entity).inverse().matrix2;
+        frame_gravity.insert(entity, world_to_frame * **gravity * body.gravity_scale);
+    }
+}
+
+pub fn integrate_angular_velocity2d(
+    time: Res<Time>,
+    mut query: Query<(
+        &mut Rotation2D,
+        &mut AngularVelocity2D,
+        Option<&Damping2D>,
+        Option<&MaxSpeed2D>,
+    )>,
+) {
+    let delta = time.delta_seconds();
+    query
+        .par_iter_mut()
+        .for_each(|(mut rotation, mut velocity, damping, max_speed)| {
+            let mut angular = velocity.to_f32();
+            if let Some(damping) = damping {
+                angular *= (-damping.angular * delta).exp();
+            }
+            if let Some(max_speed) = max_speed {
+                let max = max_speed.angular.to_f32();
+                angular = angular.clamp(-max, max);
+            }
+            if angular != velocity.to_f32() {
+                *velocity = Radians::from_f32(angular).into();
+            }
+
+            if angular * delta != 0.0 {
+                *rotation += Radians::from_f32(angular * delta);
+            }
+        });
+}
+
+pub fn integrate_scale_velocity2d(
+    time: Res<Time>,
+    mut query: Query<(&mut Scale2D, &mut ScaleVelocity2D, Option<&Damping2D>)>,
+) {
+    let delta = time.delta_seconds();
+    query
+        .par_iter_mut()
+        .for_each(|(mut scale, mut velocity, damping)| {
+            let mut rate = Vec2::from(*velocity);
+            if let Some(damping) = damping {
+                rate *= (-damping.scale * delta).exp();
+                if rate != Vec2::from(*velocity) {
+                    *velocity = rate.into();
+                }
+            }
+
+            // Scale2D can't be zero, so an axis stops short instead of collapsing
+            let step = rate * delta;
+            let new_x = scale.x + step.x;
+            let new_y = scale.y + step.y;
+            if step.x != 0.0 && new_x != 0.0 {
+                scale.x = new_x;
+            }
+            if step.y != 0.0 && new_y != 0.0 {
+                scale.y = new_y;
+            }
+        });
+}
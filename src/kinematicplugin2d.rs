@@ -0,0 +1,62 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    prelude::*,
+};
+
+// Runs before SpatialPlugin2D propagates, use FixedUpdate for deterministic movement
+pub struct KinematicPlugin2D {
+    pub schedule: InternedScheduleLabel,
+}
+
+impl KinematicPlugin2D {
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+
+    pub fn fixed() -> Self {
+        Self::new(FixedUpdate)
+    }
+}
+
+impl Plugin for KinematicPlugin2D {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Velocity2D>()
+            .register_type::<VelocityFrame2D>()
+            .register_type::<AngularVelocity2D>()
+            .register_type::<ScaleVelocity2D>()
+            .register_type::<Damping2D>()
+            .register_type::<MaxSpeed2D>()
+            .register_type::<KinematicBody2D>()
+            .register_type::<Acceleration2D>()
+            .register_type::<Forces2D>()
+            .register_type::<Gravity2D>()
+            .register_type::<Integrator2D>()
+            .init_resource::<Gravity2D>()
+            .init_resource::<Integrator2D>()
+            .add_systems(
+                self.schedule,
+                // Facing is settled first so local frame velocities follow this frame's rotation
+                (
+                    apply_forces2d,
+                    integrate_angular_velocity2d,
+                    integrate_scale_velocity2d,
+                    integrate_velocity2d,
+                    finish_forces2d,
+                )
+                    .chain()
+                    .in_set(SpatialSystems2D::Kinematics),
+            );
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for KinematicPlugin2D {
+        fn default() -> Self {
+            Self::new(Update)
+        }
+    }
+}
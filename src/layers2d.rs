@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Component, Reflect, Deref, DerefMut)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Layers2D(pub u32);
+
+impl Layers2D {
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self(u32::MAX);
+    pub const DEFAULT: Self = Self(1);
+
+    pub fn new(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub fn layer(layer: u32) -> Self {
+        assert!(layer < 32);
+        Self(1 << layer)
+    }
+
+    pub fn with(self, layer: u32) -> Self {
+        self | Self::layer(layer)
+    }
+
+    pub fn without(self, layer: u32) -> Self {
+        Self(self.0 & !Self::layer(layer).0)
+    }
+
+    pub fn contains(&self, layer: u32) -> bool {
+        self.intersects(Self::layer(layer))
+    }
+
+    pub fn intersects(&self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for Layers2D {
+        fn default() -> Self {
+            Self::DEFAULT
+        }
+    }
+}
+
+mod operators {
+    use super::Layers2D;
+    use std::ops::{BitAnd, BitOr};
+
+    impl BitOr for Layers2D {
+        type Output = Layers2D;
+        fn bitor(self, rhs: Self) -> Self::Output {
+            Layers2D(self.0 | rhs.0)
+        }
+    }
+
+    impl BitAnd for Layers2D {
+        type Output = Layers2D;
+        fn bitand(self, rhs: Self) -> Self::Output {
+            Layers2D(self.0 & rhs.0)
+        }
+    }
+}
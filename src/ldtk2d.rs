@@ -0,0 +1,648 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+#[derive(Default, Clone, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct LdtkLevel2D {
+    pub identifier: String,
+    pub iid: String,
+    // In world units
+    pub size: Vec2,
+    pub world_depth: i32,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect, Deserialize)]
+#[reflect(Default)]
+pub enum LdtkLayerKind2D {
+    #[default]
+    IntGrid,
+    Entities,
+    Tiles,
+    AutoLayer,
+}
+
+#[derive(Default, Clone, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct LdtkLayer2D {
+    pub identifier: String,
+    pub iid: String,
+    pub kind: LdtkLayerKind2D,
+    // In cells
+    pub size: UVec2,
+    pub opacity: f32,
+    // Relative to the project file
+    pub tileset: Option<String>,
+}
+
+// Position2D sits on the LDtk pivot, anchor is the same pivot as a sprite anchor
+#[derive(Default, Clone, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct LdtkEntity2D {
+    pub identifier: String,
+    pub iid: String,
+    pub size: Vec2,
+    pub anchor: Vec2,
+    pub tags: Vec<String>,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Component, Reflect, Deref, DerefMut)]
+#[reflect(Component, Default)]
+pub struct LdtkIntCell2D(pub i32);
+
+// Flips are already applied to Scale2D
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct LdtkTile2D {
+    pub id: u32,
+    // Top left of the tile in the tileset image, in pixels
+    pub source: UVec2,
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+
+// Points are converted to the same y-up GridCoord int grid cells use
+#[derive(Default, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Default)]
+pub enum LdtkValue2D {
+    #[default]
+    Null,
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    String(String),
+    Color(Color),
+    Point(GridCoord),
+    EntityRef(String),
+    Tile(URect),
+}
+
+#[derive(Default, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Default)]
+pub struct LdtkField2D {
+    pub identifier: String,
+    // One value unless the field is an array
+    pub values: Vec<LdtkValue2D>,
+}
+
+// Custom fields of a level or entity, in the order they're defined in LDtk
+#[derive(Default, Clone, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct LdtkFields2D {
+    pub fields: Vec<LdtkField2D>,
+}
+
+// An LDtk project, ready to spawn. Each level's bottom left corner is at its world position
+// with y flipped, so everything inside a level has positive y-up coordinates.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LdtkDocument2D {
+    pub name: String,
+    pub pixels_per_unit: f32,
+    project: Project,
+}
+
+#[derive(Debug)]
+pub enum LdtkImportError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    ExternalLevels,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Project {
+    #[serde(default)]
+    external_levels: bool,
+    #[serde(default)]
+    levels: Vec<Level>,
+    #[serde(default)]
+    worlds: Vec<LdtkWorld>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+struct LdtkWorld {
+    #[serde(default)]
+    levels: Vec<Level>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Level {
+    identifier: String,
+    #[serde(default)]
+    iid: String,
+    #[serde(default)]
+    world_x: f32,
+    #[serde(default)]
+    world_y: f32,
+    #[serde(default)]
+    world_depth: i32,
+    px_wid: f32,
+    px_hei: f32,
+    #[serde(default)]
+    field_instances: Vec<FieldInstance>,
+    layer_instances: Option<Vec<Layer>>,
+    external_rel_path: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Layer {
+    #[serde(rename = "__identifier")]
+    identifier: String,
+    #[serde(rename = "__type")]
+    kind: LdtkLayerKind2D,
+    #[serde(rename = "__cWid")]
+    c_wid: u32,
+    #[serde(rename = "__cHei")]
+    c_hei: u32,
+    #[serde(rename = "__gridSize")]
+    grid_size: f32,
+    #[serde(rename = "__opacity", default = "opaque")]
+    opacity: f32,
+    #[serde(rename = "__pxTotalOffsetX", default)]
+    px_total_offset_x: f32,
+    #[serde(rename = "__pxTotalOffsetY", default)]
+    px_total_offset_y: f32,
+    #[serde(rename = "__tilesetRelPath")]
+    tileset_rel_path: Option<String>,
+    #[serde(default)]
+    iid: String,
+    #[serde(default = "visible")]
+    visible: bool,
+    #[serde(default)]
+    int_grid_csv: Vec<i32>,
+    #[serde(default)]
+    grid_tiles: Vec<Tile>,
+    #[serde(default)]
+    auto_layer_tiles: Vec<Tile>,
+    #[serde(default)]
+    entity_instances: Vec<EntityInstance>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+struct Tile {
+    px: [f32; 2],
+    src: [u32; 2],
+    // Bit 0 is x, bit 1 is y
+    #[serde(default)]
+    f: u8,
+    t: u32,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EntityInstance {
+    #[serde(rename = "__identifier")]
+    identifier: String,
+    // Normalized, y-down, (0, 0) is the top left
+    #[serde(rename = "__pivot")]
+    pivot: [f32; 2],
+    #[serde(rename = "__tags", default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    iid: String,
+    width: f32,
+    height: f32,
+    // The pivot in level pixels, without the layer offset
+    px: [f32; 2],
+    #[serde(default)]
+    field_instances: Vec<FieldInstance>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+struct FieldInstance {
+    #[serde(rename = "__identifier")]
+    identifier: String,
+    #[serde(rename = "__type")]
+    kind: String,
+    #[serde(rename = "__value")]
+    value: Value,
+}
+
+fn visible() -> bool {
+    true
+}
+
+fn opaque() -> f32 {
+    1.0
+}
+
+impl LdtkDocument2D {
+    // Projects saved with separate level files need load so the levels can be found
+    pub fn from_json(text: &str) -> Result<Self, LdtkImportError> {
+        let document = Self::parse(text)?;
+        if document.project.external_levels {
+            return Err(LdtkImportError::ExternalLevels);
+        }
+        Ok(document)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LdtkImportError> {
+        let path = path.as_ref();
+        let mut document = Self::parse(&read(path)?)?;
+        if let Some(stem) = path.file_stem() {
+            document.name = stem.to_string_lossy().into_owned();
+        }
+        let directory = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        for level in document.levels_mut() {
+            if let Some(relative) = &level.external_rel_path {
+                if level.layer_instances.is_none() {
+                    let text = read(&directory.join(relative))?;
+                    *level = serde_json::from_str(&text).map_err(LdtkImportError::Json)?;
+                }
+            }
+        }
+        Ok(document)
+    }
+
+    fn parse(text: &str) -> Result<Self, LdtkImportError> {
+        Ok(Self {
+            name: "ldtk".to_string(),
+            pixels_per_unit: 1.0,
+            project: serde_json::from_str(text).map_err(LdtkImportError::Json)?,
+        })
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_pixels_per_unit(mut self, pixels_per_unit: f32) -> Self {
+        assert!(pixels_per_unit > 0.0);
+        self.pixels_per_unit = pixels_per_unit;
+        self
+    }
+
+    pub fn level_identifiers(&self) -> impl Iterator<Item = &str> {
+        self.levels().map(|level| level.identifier.as_str())
+    }
+
+    // Multi-world projects keep their levels inside each world
+    fn levels(&self) -> impl Iterator<Item = &Level> {
+        self.project
+            .levels
+            .iter()
+            .chain(self.project.worlds.iter().flat_map(|world| &world.levels))
+    }
+
+    fn levels_mut(&mut self) -> impl Iterator<Item = &mut Level> {
+        self.project.levels.iter_mut().chain(
+            self.project
+                .worlds
+                .iter_mut()
+                .flat_map(|world| &mut world.levels),
+        )
+    }
+
+    pub fn spawn(&self, world: &mut World) -> Entity {
+        let root = world
+            .spawn((SpatialBundle2D::default(), Name::new(self.name.clone())))
+            .id();
+        for level in self.levels() {
+            let level = self.spawn_level_entity(world, level);
+            world.entity_mut(level).set_parent(root);
+        }
+        root
+    }
+
+    pub fn spawn_level(&self, world: &mut World, identifier: &str) -> Option<Entity> {
+        let level = self.levels().find(|level| level.identifier == identifier)?;
+        Some(self.spawn_level_entity(world, level))
+    }
+
+    // Levels sit at their world depth, their layers are spread inside that one step with
+    // the first LDtk layer on top. Inside a layer later entries draw over earlier ones.
+    fn spawn_level_entity(&self, world: &mut World, level: &Level) -> Entity {
+        let scale = self.pixels_per_unit;
+        let entity = world
+            .spawn((
+                SpatialBundle2D {
+                    position: Position2D::new(level.world_x, -(level.world_y + level.px_hei))
+                        / scale,
+                    draw_order: DrawOrder::new(level.world_depth as f32),
+                    ..default()
+                },
+                Name::new(level.identifier.clone()),
+                LdtkLevel2D {
+                    identifier: level.identifier.clone(),
+                    iid: level.iid.clone(),
+                    size: Vec2::new(level.px_wid, level.px_hei) / scale,
+                    world_depth: level.world_depth,
+                },
+                fields(&level.field_instances, 0),
+            ))
+            .id();
+
+        let layers = level.layer_instances.as_deref().unwrap_or_default();
+        let step = 1.0 / layers.len().max(1) as f32;
+        for (index, layer) in layers.iter().enumerate() {
+            let depth = (layers.len() - 1 - index) as f32 * step;
+            let layer_entity = world
+                .spawn((
+                    SpatialBundle2D {
+                        position: Position2D::new(
+                            layer.px_total_offset_x,
+                            -layer.px_total_offset_y,
+                        ) / scale,
+                        draw_order: DrawOrder::new(depth),
+                        spatial: SpatialBundle {
+                            visibility: if layer.visible {
+                                Visibility::Inherited
+                            } else {
+                                Visibility::Hidden
+                            },
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new(layer.identifier.clone()),
+                    LdtkLayer2D {
+                        identifier: layer.identifier.clone(),
+                        iid: layer.iid.clone(),
+                        kind: layer.kind,
+                        size: UVec2::new(layer.c_wid, layer.c_hei),
+                        opacity: layer.opacity,
+                        tileset: layer.tileset_rel_path.clone(),
+                    },
+                ))
+                .set_parent(entity)
+                .id();
+            LayerSpawner {
+                level,
+                layer,
+                entity: layer_entity,
+                scale,
+                step,
+            }
+            .spawn(world);
+        }
+        entity
+    }
+}
+
+struct LayerSpawner<'a> {
+    level: &'a Level,
+    layer: &'a Layer,
+    entity: Entity,
+    scale: f32,
+    step: f32,
+}
+
+impl LayerSpawner<'_> {
+    fn spawn(&self, world: &mut World) {
+        if self.layer.kind == LdtkLayerKind2D::IntGrid {
+            self.spawn_int_grid(world);
+        }
+        self.spawn_tiles(world);
+        self.spawn_entities(world);
+    }
+
+    // Level pixels to the level's y-up space
+    fn level_point(&self, x: f32, y: f32) -> Position2D {
+        Position2D::new(x, self.level.px_hei - y) / self.scale
+    }
+
+    fn spawn_int_grid(&self, world: &mut World) {
+        let layer = self.layer;
+        let grid_size = layer.grid_size / self.scale;
+        // Cells count up from the bottom, which is wherever the last row ends
+        let bottom = (self.level.px_hei - layer.c_hei as f32 * layer.grid_size) / self.scale;
+        world
+            .entity_mut(self.entity)
+            .insert(Grid2D::square(grid_size).with_origin(Position2D::new(0.0, bottom)));
+        let width = layer.c_wid.max(1) as usize;
+        for (index, value) in layer.int_grid_csv.iter().enumerate() {
+            if *value == 0 {
+                continue;
+            }
+            let (column, row) = ((index % width) as f32, (index / width) as f32);
+            world
+                .spawn((
+                    SpatialBundle2D {
+                        position: self.level_point(
+                            (column + 0.5) * layer.grid_size,
+                            (row + 0.5) * layer.grid_size,
+                        ),
+                        ..default()
+                    },
+                    grid_coord(layer, column as i32, row as i32),
+                    LdtkIntCell2D(*value),
+                ))
+                .set_parent(self.entity);
+        }
+    }
+
+    fn spawn_tiles(&self, world: &mut World) {
+        let layer = self.layer;
+        let tiles: Vec<&Tile> = layer
+            .grid_tiles
+            .iter()
+            .chain(&layer.auto_layer_tiles)
+            .collect();
+        let step = self.step / tiles.len().max(1) as f32;
+        let half = layer.grid_size * 0.5;
+        for (index, tile) in tiles.into_iter().enumerate() {
+            let flip_x = tile.f & 1 != 0;
+            let flip_y = tile.f & 2 != 0;
+            world
+                .spawn((
+                    SpatialBundle2D {
+                        position: self.level_point(tile.px[0] + half, tile.px[1] + half),
+                        scale: Scale2D::new(
+                            if flip_x { -1.0 } else { 1.0 },
+                            if flip_y { -1.0 } else { 1.0 },
+                        ),
+                        draw_order: DrawOrder::new(index as f32 * step),
+                        ..default()
+                    },
+                    LdtkTile2D {
+                        id: tile.t,
+                        source: UVec2::new(tile.src[0], tile.src[1]),
+                        flip_x,
+                        flip_y,
+                    },
+                ))
+                .set_parent(self.entity);
+        }
+    }
+
+    fn spawn_entities(&self, world: &mut World) {
+        let layer = self.layer;
+        let step = self.step / layer.entity_instances.len().max(1) as f32;
+        for (index, instance) in layer.entity_instances.iter().enumerate() {
+            let size = Vec2::new(instance.width, instance.height) / self.scale;
+            let anchor = Vec2::new(instance.pivot[0] - 0.5, 0.5 - instance.pivot[1]);
+            let mut entity = world.spawn((
+                SpatialBundle2D {
+                    position: self.level_point(instance.px[0], instance.px[1]),
+                    draw_order: DrawOrder::new(index as f32 * step),
+                    ..default()
+                },
+                Name::new(instance.identifier.clone()),
+                LdtkEntity2D {
+                    identifier: instance.identifier.clone(),
+                    iid: instance.iid.clone(),
+                    size,
+                    anchor,
+                    tags: instance.tags.clone(),
+                },
+                fields(&instance.field_instances, layer.c_hei),
+            ));
+            if size.x > 0.0 && size.y > 0.0 {
+                entity.insert(Bounds2D::rect(size).with_pivot(anchor));
+            }
+            entity.set_parent(self.entity);
+        }
+    }
+}
+
+impl LdtkFields2D {
+    pub fn field(&self, identifier: &str) -> Option<&LdtkField2D> {
+        self.fields
+            .iter()
+            .find(|field| field.identifier == identifier)
+    }
+
+    pub fn get(&self, identifier: &str) -> Option<&LdtkValue2D> {
+        self.field(identifier)?.values.first()
+    }
+
+    pub fn int(&self, identifier: &str) -> Option<i64> {
+        match self.get(identifier)? {
+            LdtkValue2D::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn float(&self, identifier: &str) -> Option<f64> {
+        match self.get(identifier)? {
+            LdtkValue2D::Float(value) => Some(*value),
+            LdtkValue2D::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    pub fn bool(&self, identifier: &str) -> Option<bool> {
+        match self.get(identifier)? {
+            LdtkValue2D::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    // Also covers enums, file paths and multiline text
+    pub fn string(&self, identifier: &str) -> Option<&str> {
+        match self.get(identifier)? {
+            LdtkValue2D::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn color(&self, identifier: &str) -> Option<Color> {
+        match self.get(identifier)? {
+            LdtkValue2D::Color(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn point(&self, identifier: &str) -> Option<GridCoord> {
+        match self.get(identifier)? {
+            LdtkValue2D::Point(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn entity_ref(&self, identifier: &str) -> Option<&str> {
+        match self.get(identifier)? {
+            LdtkValue2D::EntityRef(iid) => Some(iid),
+            _ => None,
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, LdtkImportError> {
+    std::fs::read_to_string(PathBuf::from(path)).map_err(LdtkImportError::Io)
+}
+
+fn grid_coord(layer: &Layer, column: i32, row: i32) -> GridCoord {
+    GridCoord::new(column, layer.c_hei as i32 - 1 - row)
+}
+
+// Rows are needed to flip points into y-up cells, levels have no grid so they pass 0
+fn fields(instances: &[FieldInstance], rows: u32) -> LdtkFields2D {
+    LdtkFields2D {
+        fields: instances
+            .iter()
+            .map(|instance| {
+                let kind = instance
+                    .kind
+                    .strip_prefix("Array<")
+                    .and_then(|kind| kind.strip_suffix('>'));
+                let values = match (kind, &instance.value) {
+                    (Some(kind), Value::Array(values)) => values
+                        .iter()
+                        .map(|value| field_value(kind, value, rows))
+                        .collect(),
+                    _ => vec![field_value(&instance.kind, &instance.value, rows)],
+                };
+                LdtkField2D {
+                    identifier: instance.identifier.clone(),
+                    values,
+                }
+            })
+            .collect(),
+    }
+}
+
+fn field_value(kind: &str, value: &Value, rows: u32) -> LdtkValue2D {
+    let integer = |key: &str| value.get(key).and_then(Value::as_i64).unwrap_or_default();
+    match (kind, value) {
+        (_, Value::Null) => LdtkValue2D::Null,
+        ("Int", value) => value.as_i64().map_or(LdtkValue2D::Null, LdtkValue2D::Int),
+        ("Float", value) => value.as_f64().map_or(LdtkValue2D::Null, LdtkValue2D::Float),
+        ("Bool", Value::Bool(value)) => LdtkValue2D::Bool(*value),
+        ("Color", Value::String(hex)) => {
+            Srgba::hex(hex).map_or(LdtkValue2D::Null, |color| LdtkValue2D::Color(color.into()))
+        }
+        ("Point", _) => LdtkValue2D::Point(GridCoord::new(
+            integer("cx") as i32,
+            rows as i32 - 1 - integer("cy") as i32,
+        )),
+        ("EntityRef", _) => value
+            .get("entityIid")
+            .and_then(Value::as_str)
+            .map_or(LdtkValue2D::Null, |iid| {
+                LdtkValue2D::EntityRef(iid.to_string())
+            }),
+        ("Tile", _) => {
+            let min = UVec2::new(integer("x") as u32, integer("y") as u32);
+            let size = UVec2::new(integer("w") as u32, integer("h") as u32);
+            LdtkValue2D::Tile(URect::from_corners(min, min + size))
+        }
+        // Strings, multilines, file paths and both kinds of enum
+        (_, Value::String(value)) => LdtkValue2D::String(value.clone()),
+        (_, Value::Bool(value)) => LdtkValue2D::Bool(*value),
+        _ => LdtkValue2D::Null,
+    }
+}
+
+impl fmt::Display for LdtkImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::Json(error) => write!(f, "{error}"),
+            Self::ExternalLevels => write!(
+                f,
+                "LDtk project stores its levels in separate files, load it from its path"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LdtkImportError {}
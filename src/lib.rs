@@ -1,53 +1,289 @@
-#![allow(clippy::type_complexity)]
+#![allow(clippy::type_complexity, clippy::too_many_arguments)]
+mod animation2d;
+mod animation_systems;
+mod bounds2d;
+mod bounds_systems;
+mod camera_view2d;
+mod clamp2d;
 mod compass;
 mod compass_halfwinds;
 mod compass_rose;
+mod constraint_systems;
 mod degrees;
+#[cfg(feature = "serde")]
+mod document2d;
 mod draw_order;
+mod easing;
+mod follow2d;
+mod follow_path2d;
+mod global_spatial2d;
+mod grid2d;
+mod grid_coord;
+mod hex_coord;
+mod hex_direction;
+mod hex_layout;
+mod index_systems;
+mod kinematic_body2d;
+mod kinematic_systems;
+mod kinematicplugin2d;
+mod layers2d;
+#[cfg(feature = "ldtk")]
+mod ldtk2d;
+mod look_at2d;
+mod motion_systems;
+mod orbit2d;
+mod picking2d;
 mod position2d;
+mod projection2d;
 mod propagation_systems;
+mod quadtree2d;
 mod radians;
+mod raycast2d;
 mod rotation2d;
 mod scale2d;
+mod scene_systems;
+mod smoothing;
+mod spatial_grid2d;
+mod spatial_index2d;
 mod spatialbundle2d;
 mod spatialplugin2d;
+mod svg_export2d;
+#[cfg(feature = "testing")]
+mod testing2d;
+#[cfg(feature = "tiled")]
+mod tiled2d;
+mod tween2d;
+mod tween_systems;
+mod tweenplugin2d;
+mod velocity2d;
 
 pub mod components {
+    pub use crate::animation2d::Animated2D;
+    pub use crate::bounds2d::Bounds2D;
+    pub use crate::bounds2d::HierarchyBounds2D;
+    pub use crate::bounds2d::WorldBounds2D;
+    pub use crate::clamp2d::ClampPosition2D;
+    pub use crate::clamp2d::ClampRotation2D;
     pub use crate::compass::Compass;
     pub use crate::compass_halfwinds::CompassHalfwinds;
     pub use crate::compass_rose::CompassRose;
     pub use crate::draw_order::DrawOrder;
+    pub use crate::follow2d::Follow2D;
+    pub use crate::follow_path2d::FollowPath2D;
+    pub use crate::grid2d::Grid2D;
+    pub use crate::grid2d::SnapToGrid;
+    pub use crate::grid_coord::GridCoord;
+    pub use crate::hex_coord::HexCoord;
+    pub use crate::hex_direction::FlatHexDirection;
+    pub use crate::hex_direction::PointyHexDirection;
+    pub use crate::hex_layout::HexLayout;
+    pub use crate::kinematic_body2d::Acceleration2D;
+    pub use crate::kinematic_body2d::Forces2D;
+    pub use crate::kinematic_body2d::KinematicBody2D;
+    pub use crate::kinematic_body2d::KinematicBundle2D;
+    pub use crate::layers2d::Layers2D;
+    pub use crate::look_at2d::LookAt2D;
+    pub use crate::orbit2d::Orbit2D;
     pub use crate::position2d::Position2D;
     pub use crate::position2d::PositionPropagation;
+    pub use crate::projection2d::Elevation2D;
+    pub use crate::projection2d::Projection2D;
     pub use crate::rotation2d::Rotation2D;
     pub use crate::rotation2d::RotationPropagation;
     pub use crate::scale2d::Scale2D;
     pub use crate::scale2d::ScalePropagation;
+    pub use crate::smoothing::SmoothPosition2D;
+    pub use crate::smoothing::SmoothRotation2D;
+    pub use crate::smoothing::SmoothScale2D;
     pub use crate::spatialbundle2d::SpatialBundle2D;
     pub use crate::spatialbundle2d::SpatialBundle2DRaw;
+    pub use crate::tween2d::Tween2D;
+    pub use crate::velocity2d::AngularVelocity2D;
+    pub use crate::velocity2d::Damping2D;
+    pub use crate::velocity2d::MaxSpeed2D;
+    pub use crate::velocity2d::ScaleVelocity2D;
+    pub use crate::velocity2d::Velocity2D;
+    pub use crate::velocity2d::VelocityFrame2D;
 }
 
 pub mod math {
+    pub use crate::animation2d::Interpolation2D;
+    pub use crate::animation2d::SpatialCurves2D;
+    pub use crate::bounds2d::Obb2D;
+    pub use crate::camera_view2d::CameraView2D;
+    pub use crate::clamp2d::ClampMode2D;
+    pub use crate::clamp2d::ClampRegion2D;
+    pub use crate::clamp2d::ClampSpace2D;
     pub use crate::degrees::Degrees;
+    pub use crate::easing::Easing;
+    pub use crate::follow2d::FollowOffset2D;
+    pub use crate::follow2d::FollowSmoothing2D;
+    pub use crate::follow_path2d::PathCurve2D;
+    pub use crate::follow_path2d::PathEventKind2D;
+    pub use crate::follow_path2d::PathLoop2D;
+    pub use crate::grid2d::CellAnchor;
+    pub use crate::hex_layout::HexOrientation;
+    pub use crate::look_at2d::LookTarget2D;
+    pub use crate::orbit2d::OrbitCenter2D;
+    pub use crate::projection2d::ProjectionMode2D;
     pub use crate::radians::Radians;
+    pub use crate::raycast2d::RayHit2D;
+    pub use crate::smoothing::exp_decay;
+    pub use crate::smoothing::exp_decay_angle;
+    pub use crate::smoothing::exp_decay_degrees;
+    pub use crate::smoothing::smooth_damp;
+    pub use crate::smoothing::smooth_damp_angle;
+    pub use crate::smoothing::smooth_damp_degrees;
+    pub use crate::smoothing::SmoothMode2D;
+    pub use crate::tween2d::DrawOrderLens;
+    pub use crate::tween2d::PositionLens;
+    pub use crate::tween2d::RotationLens;
+    pub use crate::tween2d::RotationTween2D;
+    pub use crate::tween2d::ScaleLens;
+    pub use crate::tween2d::TweenEventKind2D;
+    pub use crate::tween2d::TweenRepeat2D;
+}
+
+pub mod resources {
+    pub use crate::kinematic_body2d::Gravity2D;
+    pub use crate::kinematic_body2d::Integrator2D;
+    pub use crate::quadtree2d::Quadtree2D;
+    pub use crate::spatial_grid2d::SpatialGrid2D;
+}
+
+pub mod events {
+    pub use crate::follow_path2d::PathEvent2D;
+    pub use crate::tween2d::TweenEvent2D;
+}
+
+#[cfg(feature = "serde")]
+pub mod serialization {
+    pub use crate::document2d::SpatialDocument2D;
+    pub use crate::document2d::SpatialDocumentError;
+    pub use crate::document2d::SpatialNode2D;
+    pub use crate::document2d::SPATIAL_DOCUMENT_VERSION;
+}
+
+pub mod export {
+    pub use crate::svg_export2d::SvgExport2D;
+}
+
+#[cfg(feature = "testing")]
+pub mod testing {
+    pub use crate::testing2d::assert_golden_text;
+    pub use crate::testing2d::SnapshotEntry2D;
+    pub use crate::testing2d::SnapshotError2D;
+    pub use crate::testing2d::SpatialSnapshot2D;
+    pub use crate::testing2d::TestHierarchy2D;
+    pub use crate::testing2d::TestNode2D;
+    pub use crate::testing2d::SNAPSHOT_BLESS_VAR;
+}
+
+#[cfg(any(feature = "tiled", feature = "ldtk"))]
+pub mod import {
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkDocument2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkEntity2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkField2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkFields2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkImportError;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkIntCell2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkLayer2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkLayerKind2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkLevel2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkTile2D;
+    #[cfg(feature = "ldtk")]
+    pub use crate::ldtk2d::LdtkValue2D;
+    #[cfg(feature = "tiled")]
+    pub use crate::tiled2d::TiledDocument2D;
+    #[cfg(feature = "tiled")]
+    pub use crate::tiled2d::TiledImportError;
+    #[cfg(feature = "tiled")]
+    pub use crate::tiled2d::TiledLayer2D;
+    #[cfg(feature = "tiled")]
+    pub use crate::tiled2d::TiledLayerKind2D;
+    #[cfg(feature = "tiled")]
+    pub use crate::tiled2d::TiledMap2D;
+    #[cfg(feature = "tiled")]
+    pub use crate::tiled2d::TiledObject2D;
+    #[cfg(feature = "tiled")]
+    pub use crate::tiled2d::TiledShape2D;
+    #[cfg(feature = "tiled")]
+    pub use crate::tiled2d::TiledTile2D;
+}
+
+pub mod params {
+    pub use crate::camera_view2d::Cameras2D;
+    pub use crate::global_spatial2d::GlobalSpatial2D;
+    pub use crate::picking2d::Picking2D;
+    pub use crate::raycast2d::Raycast2D;
+}
+
+pub mod traits {
+    pub use crate::smoothing::Smoothable;
+    pub use crate::spatial_index2d::SpatialIndex2D;
+    pub use crate::tween2d::Lens2D;
 }
 
 pub mod plugins {
+    pub use crate::kinematicplugin2d::KinematicPlugin2D;
     pub use crate::spatialplugin2d::SpatialPlugin2D;
+    pub use crate::tweenplugin2d::TweenPlugin2D;
 }
 
 pub mod systems {
+    pub use crate::animation_systems::record_animated2d;
+    pub use crate::animation_systems::sync_animated2d;
+    pub use crate::bounds_systems::update_hierarchy_bounds2d;
+    pub use crate::bounds_systems::update_world_bounds2d;
+    pub use crate::constraint_systems::clamp_position2d;
+    pub use crate::constraint_systems::clamp_rotation2d;
+    pub use crate::constraint_systems::follow2d;
+    pub use crate::constraint_systems::look_at2d;
+    pub use crate::index_systems::update_spatial_index2d;
+    pub use crate::kinematic_systems::apply_forces2d;
+    pub use crate::kinematic_systems::finish_forces2d;
+    pub use crate::kinematic_systems::integrate_angular_velocity2d;
+    pub use crate::kinematic_systems::integrate_scale_velocity2d;
+    pub use crate::kinematic_systems::integrate_velocity2d;
+    pub use crate::motion_systems::follow_path2d;
+    pub use crate::motion_systems::orbit2d;
+    pub use crate::motion_systems::smooth_position2d;
+    pub use crate::motion_systems::smooth_rotation2d;
+    pub use crate::motion_systems::smooth_scale2d;
     pub use crate::propagation_systems::propagate_spatial2d;
     pub use crate::propagation_systems::update_compass_from_rotation2d;
     pub use crate::propagation_systems::update_compass_halfwinds_from_rotation2d;
     pub use crate::propagation_systems::update_compass_rose_from_rotation2d;
     pub use crate::propagation_systems::SpatialSystems2D;
+    pub use crate::scene_systems::complete_spatial2d;
+    pub use crate::tween_systems::tween2d;
 }
 
 pub mod prelude {
     pub use crate::components::*;
+    pub use crate::events::*;
+    pub use crate::export::*;
+    #[cfg(any(feature = "tiled", feature = "ldtk"))]
+    pub use crate::import::*;
     pub use crate::math::*;
+    pub use crate::params::*;
 
     pub use crate::plugins::*;
+    pub use crate::resources::*;
+    #[cfg(feature = "serde")]
+    pub use crate::serialization::*;
     pub use crate::systems::*;
+    #[cfg(feature = "testing")]
+    pub use crate::testing::*;
+    pub use crate::traits::*;
 }
@@ -0,0 +1,107 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum LookTarget2D {
+    Entity(Entity),
+    // World space
+    Position(Position2D),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, MapEntities)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct LookAt2D {
+    pub target: LookTarget2D,
+    pub offset: Radians,
+    pub max_turn_rate: Radians,
+    // Min and max of the entity's own Rotation2D, so relative to the parent
+    pub limits: Option<(Radians, Radians)>,
+}
+
+impl LookAt2D {
+    pub fn new(target: LookTarget2D) -> Self {
+        Self {
+            target,
+            offset: Radians::ZERO,
+            max_turn_rate: Radians::from_f32(f32::INFINITY),
+            limits: None,
+        }
+    }
+
+    pub fn entity(target: Entity) -> Self {
+        Self::new(LookTarget2D::Entity(target))
+    }
+
+    pub fn position(target: Position2D) -> Self {
+        Self::new(LookTarget2D::Position(target))
+    }
+
+    // For sprites that don't face along +x
+    pub fn with_offset(mut self, offset: impl Into<Rotation2D>) -> Self {
+        self.offset = offset.into().radians();
+        self
+    }
+
+    pub fn with_max_turn_rate(mut self, per_second: Radians) -> Self {
+        self.max_turn_rate = per_second;
+        self
+    }
+
+    pub fn with_limits(mut self, min: Radians, max: Radians) -> Self {
+        assert!(min.to_f32() <= max.to_f32());
+        self.limits = Some((min, max));
+        self
+    }
+
+    // Picks the local rotation to move to, going the long way round when the short one
+    // would cross outside the limits
+    pub fn step(&self, current: Radians, desired: Radians, delta: f32) -> Radians {
+        let (current, desired, arc) = match self.limits {
+            Some((min, max)) => {
+                let mid = (min.to_f32() + max.to_f32()) * 0.5;
+                let unwrap =
+                    |angle: Radians| mid + Radians::from_f32(mid).shortest_to(angle).to_f32();
+                let current = unwrap(current).clamp(min.to_f32(), max.to_f32());
+                let desired = unwrap(desired).clamp(min.to_f32(), max.to_f32());
+                (current, desired, desired - current)
+            }
+            None => (
+                current.to_f32(),
+                desired.to_f32(),
+                current.shortest_to(desired).to_f32(),
+            ),
+        };
+        let max_step = self.max_turn_rate.to_f32() * delta;
+        if self.max_turn_rate.is_infinite() || arc.abs() <= max_step {
+            return Radians::from_f32(desired);
+        }
+        Radians::from_f32(current + arc.signum() * max_step)
+    }
+}
+
+impl MapEntities for LookAt2D {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        if let LookTarget2D::Entity(target) = &mut self.target {
+            *target = entity_mapper.map_entity(*target);
+        }
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for LookAt2D {
+        fn default() -> Self {
+            Self::position(Position2D::ZERO)
+        }
+    }
+}
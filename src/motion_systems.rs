@@ -0,0 +1,154 @@
+use crate::constraint_systems::dependency_depth;
+use crate::prelude::*;
+use bevy::{math::Affine2, prelude::*, utils::HashMap};
+
+pub fn orbit2d(
+    time: Res<Time>,
+    parents: Query<&Parent>,
+    mut set: ParamSet<(
+        GlobalSpatial2D,
+        Query<(
+            Entity,
+            &mut Position2D,
+            &mut Rotation2D,
+            &mut Orbit2D,
+            &RotationPropagation,
+        )>,
+    )>,
+    mut order: Local<Vec<(usize, Entity)>>,
+) {
+    // Moons around orbiting planets need the planet placed first
+    let centers: HashMap<Entity, Entity> = set
+        .p1()
+        .iter()
+        .filter_map(|(entity, _, _, orbit, _)| match orbit.center {
+            OrbitCenter2D::Entity(center) => Some((entity, center)),
+            OrbitCenter2D::Position(_) => None,
+        })
+        .collect();
+    let mut depths = HashMap::default();
+    order.clear();
+    for (entity, ..) in set.p1().iter() {
+        let depth = dependency_depth(entity, &centers, &parents, &mut depths);
+        order.push((depth, entity));
+    }
+    order.sort();
+
+    let delta = time.delta_seconds();
+    for (_, entity) in order.iter() {
+        let spatial = set.p0();
+        let center = match centers.get(entity) {
+            Some(center) => spatial.world_position(*center),
+            None => None,
+        };
+        let parent_from_world = spatial.parent_affine(*entity).inverse();
+        let parent_rotation = spatial.parent_rotation(*entity);
+
+        let mut orbits = set.p1();
+        let Ok((_, mut position, mut rotation, mut orbit, r_prop)) = orbits.get_mut(*entity) else {
+            continue;
+        };
+        let center = match orbit.center {
+            OrbitCenter2D::Entity(_) => match center {
+                Some(center) => center,
+                None => continue,
+            },
+            OrbitCenter2D::Position(center) => center,
+        };
+        orbit.angle = (orbit.angle + orbit.angular_speed * delta).wrapped();
+
+        let world = Vec2::from(center) + orbit.offset();
+        position.set_if_neq(parent_from_world.transform_point2(world).into());
+        if orbit.face_tangent {
+            let facing = Radians::from_f32(orbit.tangent().to_angle());
+            let local = match r_prop {
+                RotationPropagation::Relative => facing - parent_rotation.radians(),
+                RotationPropagation::Absolute => facing,
+            };
+            rotation.set_if_neq(local.into());
+        }
+    }
+}
+
+pub fn follow_path2d(
+    time: Res<Time>,
+    mut set: ParamSet<(
+        GlobalSpatial2D,
+        Query<(
+            Entity,
+            &mut Position2D,
+            &mut Rotation2D,
+            &mut FollowPath2D,
+            &RotationPropagation,
+        )>,
+    )>,
+    mut events: EventWriter<PathEvent2D>,
+) {
+    // Absolute rotations face along the path in world space, so they need the parent's frame
+    let facing_absolute: Vec<Entity> = set
+        .p1()
+        .iter()
+        .filter(|(_, _, _, path, r_prop)| {
+            path.face_along && **r_prop == RotationPropagation::Absolute
+        })
+        .map(|(entity, ..)| entity)
+        .collect();
+    let spatial = set.p0();
+    let parents: HashMap<Entity, Affine2> = facing_absolute
+        .into_iter()
+        .map(|entity| (entity, spatial.parent_affine(entity)))
+        .collect();
+
+    let delta = time.delta_seconds();
+    for (entity, mut position, mut rotation, mut path, _) in set.p1().iter_mut() {
+        if path.is_finished() {
+            continue;
+        }
+        path.advance(delta, |kind| {
+            events.send(PathEvent2D { entity, kind });
+        });
+        position.set_if_neq(path.position());
+        if path.face_along {
+            let tangent = match parents.get(&entity) {
+                Some(parent) => parent.transform_vector2(path.tangent()),
+                None => path.tangent(),
+            };
+            if tangent != Vec2::ZERO {
+                rotation.set_if_neq(Rotation2D::from_f32_radians(tangent.to_angle()));
+            }
+        }
+    }
+}
+
+pub fn smooth_position2d(
+    time: Res<Time>,
+    mut query: Query<(&mut Position2D, &mut SmoothPosition2D)>,
+) {
+    let delta = time.delta_seconds();
+    query.par_iter_mut().for_each(|(mut position, mut smooth)| {
+        let next = smooth.step(*position, delta);
+        position.set_if_neq(next);
+    });
+}
+
+pub fn smooth_rotation2d(
+    time: Res<Time>,
+    mut query: Query<(&mut Rotation2D, &mut SmoothRotation2D)>,
+) {
+    let delta = time.delta_seconds();
+    query.par_iter_mut().for_each(|(mut rotation, mut smooth)| {
+        let next = smooth.step(*rotation, delta);
+        rotation.set_if_neq(next);
+    });
+}
+
+pub fn smooth_scale2d(time: Res<Time>, mut query: Query<(&mut Scale2D, &mut SmoothScale2D)>) {
+    let delta = time.delta_seconds();
+    query.par_iter_mut().for_each(|(mut scale, mut smooth)| {
+        let next = smooth.step(*scale, delta);
+        // Scale2D can't hold zero, so an axis crossing it waits a frame
+        if next.x != 0.0 && next.y != 0.0 {
+            scale.set_if_neq(Scale2D::from_vec(next));
+        }
+    });
+}
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum OrbitCenter2D {
+    Entity(Entity),
+    // World space
+    Position(Position2D),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, MapEntities)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Orbit2D {
+    pub center: OrbitCenter2D,
+    // Different x and y radii give an elliptical orbit
+    pub radii: Vec2,
+    pub angular_speed: Radians,
+    pub angle: Radians,
+    pub face_tangent: bool,
+}
+
+impl Orbit2D {
+    pub fn new(center: OrbitCenter2D, radius: f32) -> Self {
+        Self {
+            center,
+            radii: Vec2::splat(radius),
+            angular_speed: Radians::ZERO,
+            angle: Radians::ZERO,
+            face_tangent: false,
+        }
+    }
+
+    pub fn entity(center: Entity, radius: f32) -> Self {
+        Self::new(OrbitCenter2D::Entity(center), radius)
+    }
+
+    pub fn position(center: Position2D, radius: f32) -> Self {
+        Self::new(OrbitCenter2D::Position(center), radius)
+    }
+
+    pub fn with_radii(mut self, x: f32, y: f32) -> Self {
+        self.radii = Vec2::new(x, y);
+        self
+    }
+
+    pub fn with_speed(mut self, speed: impl Into<AngularVelocity2D>) -> Self {
+        self.angular_speed = speed.into().0;
+        self
+    }
+
+    pub fn with_phase(mut self, angle: impl Into<Rotation2D>) -> Self {
+        self.angle = angle.into().radians();
+        self
+    }
+
+    pub fn facing_tangent(mut self) -> Self {
+        self.face_tangent = true;
+        self
+    }
+
+    pub fn offset(&self) -> Vec2 {
+        let angle = self.angle.to_f32();
+        Vec2::new(angle.cos(), angle.sin()) * self.radii
+    }
+
+    // Direction of travel, reversed for negative speeds
+    pub fn tangent(&self) -> Vec2 {
+        let angle = self.angle.to_f32();
+        let tangent = Vec2::new(-angle.sin(), angle.cos()) * self.radii;
+        if self.angular_speed.to_f32() < 0.0 {
+            -tangent
+        } else {
+            tangent
+        }
+    }
+}
+
+impl MapEntities for Orbit2D {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        if let OrbitCenter2D::Entity(center) = &mut self.center {
+            *center = entity_mapper.map_entity(*center);
+        }
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for Orbit2D {
+        fn default() -> Self {
+            Self::position(Position2D::ZERO, 1.0)
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        query::{QueryData, QueryFilter},
+        system::SystemParam,
+    },
+    prelude::*,
+};
+
+#[derive(SystemParam)]
+pub struct Picking2D<'w, 's> {
+    targets: Query<'w, 's, (Entity, &'static WorldBounds2D, &'static GlobalTransform)>,
+    index: Option<Res<'w, Quadtree2D>>,
+}
+
+impl<'w, 's> Picking2D<'w, 's> {
+    // The z written by propagate_spatial2d is the DrawOrder accumulated down the hierarchy
+    // With a quadtree only the entries overlapping `area` are tested, otherwise every target is
+    fn sorted(&self, area: Rect, hit: impl Fn(Entity, &WorldBounds2D) -> bool) -> Vec<Entity> {
+        let candidates: Vec<_> = match &self.index {
+            Some(index) => index
+                .within_rect(area)
+                .into_iter()
+                .filter_map(|entity| self.targets.get(entity).ok())
+                .collect(),
+            None => self.targets.iter().collect(),
+        };
+        let mut hits: Vec<(f32, Entity)> = candidates
+            .into_iter()
+            .filter(|(entity, bounds, _)| hit(*entity, bounds))
+            .map(|(entity, _, transform)| (transform.translation().z, entity))
+            .collect();
+        hits.sort_by(|a, b| b.0.total_cmp(&a.0).then(b.1.cmp(&a.1)));
+        hits.into_iter().map(|(_, entity)| entity).collect()
+    }
+
+    pub fn pick_point_by(&self, point: Position2D, filter: impl Fn(Entity) -> bool) -> Vec<Entity> {
+        let point = Vec2::from(point);
+        self.sorted(
+            Rect::from_center_size(point, Vec2::ZERO),
+            |entity, bounds| bounds.contains_point(point) && filter(entity),
+        )
+    }
+
+    pub fn pick_point(&self, point: Position2D) -> Vec<Entity> {
+        self.pick_point_by(point, |_| true)
+    }
+
+    pub fn pick_point_filtered<D: QueryData, F: QueryFilter>(
+        &self,
+        point: Position2D,
+        query: &Query<D, F>,
+    ) -> Vec<Entity> {
+        self.pick_point_by(point, |entity| query.contains(entity))
+    }
+
+    pub fn pick_rect_by(&self, rect: Rect, filter: impl Fn(Entity) -> bool) -> Vec<Entity> {
+        self.sorted(rect, |entity, bounds| {
+            bounds.intersects_rect(rect) && filter(entity)
+        })
+    }
+
+    pub fn pick_rect(&self, rect: Rect) -> Vec<Entity> {
+        self.pick_rect_by(rect, |_| true)
+    }
+
+    pub fn pick_rect_filtered<D: QueryData, F: QueryFilter>(
+        &self,
+        rect: Rect,
+        query: &Query<D, F>,
+    ) -> Vec<Entity> {
+        self.pick_rect_by(rect, |entity| query.contains(entity))
+    }
+
+    pub fn topmost(&self, point: Position2D) -> Option<Entity> {
+        self.pick_point(point).first().copied()
+    }
+
+    pub fn topmost_filtered<D: QueryData, F: QueryFilter>(
+        &self,
+        point: Position2D,
+        query: &Query<D, F>,
+    ) -> Option<Entity> {
+        self.pick_point_filtered(point, query).first().copied()
+    }
+}
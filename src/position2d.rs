@@ -3,6 +3,9 @@ use bevy::prelude::*;
 
 #[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct Position2D {
     pub x: f32,
     pub y: f32,
@@ -10,6 +13,8 @@ pub struct Position2D {
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Component, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub enum PositionPropagation {
     #[default]
     Relative,
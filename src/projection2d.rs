@@ -0,0 +1,100 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum ProjectionMode2D {
+    #[default]
+    Flat,
+    Isometric,
+    Dimetric {
+        ratio: f32,
+    },
+}
+
+// Read from hierarchy roots, the resource covers roots without one. Position2D is then on the
+// ground plane and only world translations are projected, rotation and scale are left alone.
+// Flat is the same as no projection, so depth_scale and Elevation2D do nothing with it.
+#[derive(Default, Clone, Copy, PartialEq, Debug, Resource, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Resource, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Projection2D {
+    pub mode: ProjectionMode2D,
+    pub depth_scale: f32,
+}
+
+// Screen space height above the ground, added to the parent's. Without a projection, or with a
+// flat one, it does nothing.
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect, Deref, DerefMut)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Elevation2D(pub f32);
+
+impl ProjectionMode2D {
+    pub fn matrix(&self) -> Mat2 {
+        let ratio = match self {
+            Self::Flat => return Mat2::IDENTITY,
+            // Vertical squash of a true isometric view, tan(30)
+            Self::Isometric => 0.577_350_3,
+            Self::Dimetric { ratio } => *ratio,
+        };
+        Mat2::from_diagonal(Vec2::new(1.0, ratio)) * Mat2::from_angle(std::f32::consts::FRAC_PI_4)
+    }
+}
+
+impl Projection2D {
+    pub const FLAT: Self = Self {
+        mode: ProjectionMode2D::Flat,
+        depth_scale: 0.0,
+    };
+
+    pub fn new(mode: ProjectionMode2D) -> Self {
+        Self {
+            mode,
+            depth_scale: 0.0,
+        }
+    }
+
+    pub fn isometric() -> Self {
+        Self::new(ProjectionMode2D::Isometric).with_depth_scale(0.001)
+    }
+
+    // 2:1 pixel art style projection
+    pub fn dimetric() -> Self {
+        Self::new(ProjectionMode2D::Dimetric { ratio: 0.5 }).with_depth_scale(0.001)
+    }
+
+    pub fn with_depth_scale(mut self, depth_scale: f32) -> Self {
+        self.depth_scale = depth_scale;
+        self
+    }
+
+    pub fn is_flat(&self) -> bool {
+        self.mode == ProjectionMode2D::Flat
+    }
+
+    pub fn project(&self, ground: Position2D) -> Vec2 {
+        self.mode.matrix() * Vec2::from(ground)
+    }
+
+    pub fn project_elevated(&self, ground: Position2D, elevation: f32) -> Vec2 {
+        self.project(ground) + Vec2::new(0.0, elevation)
+    }
+
+    pub fn unproject(&self, screen: Vec2) -> Position2D {
+        (self.mode.matrix().inverse() * screen).into()
+    }
+
+    pub fn unproject_elevated(&self, screen: Vec2, elevation: f32) -> Position2D {
+        self.unproject(screen - Vec2::new(0.0, elevation))
+    }
+
+    // Things lower on screen are closer to the viewer, so they get pushed forward
+    pub fn depth(&self, ground: Position2D) -> f32 {
+        -self.project(ground).y * self.depth_scale
+    }
+}
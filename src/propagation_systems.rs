@@ -1,14 +1,46 @@
 use crate::prelude::*;
-use bevy::prelude::*;
+use bevy::{math::Affine2, prelude::*, utils::HashSet};
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum SpatialSystems2D {
+    Tween,
+    Animation,
+    Kinematics,
+    Constraints,
     Propagate,
+    // After TransformPropagate in PostUpdate, anything reading WorldBounds2D or a
+    // spatial index earlier in the frame sees the previous frame's values
+    Bounds,
+    Index,
 }
 
 pub fn propagate_spatial2d(
     mut query: Query<(
+        Entity,
         &mut Transform,
+        (
+            &Position2D,
+            &Rotation2D,
+            &Scale2D,
+            &DrawOrder,
+            &RotationPropagation,
+            &PositionPropagation,
+            &ScalePropagation,
+        ),
+        Option<&Parent>,
+        Option<&SnapToGrid>,
+        Option<&Elevation2D>,
+    )>,
+    spatial: GlobalSpatial2D,
+    all_parent_grids: Query<&Grid2D, With<Children>>,
+    grid: Option<Res<Grid2D>>,
+    roots: Query<(Entity, Option<&Projection2D>), (With<Position2D>, Without<Parent>)>,
+    all_children: Query<&Children>,
+    projection: Option<Res<Projection2D>>,
+    mut projected: Local<HashSet<Entity>>,
+) {
+    // The transform before projection, so its translation is on the ground plane
+    let ground = |(position, rotation, scale, draw_order, r_prop, p_prop, s_prop): (
         &Position2D,
         &Rotation2D,
         &Scale2D,
@@ -16,49 +48,174 @@ pub fn propagate_spatial2d(
         &RotationPropagation,
         &PositionPropagation,
         &ScalePropagation,
-        Option<&Parent>,
-    )>,
-    all_parent_rotations: Query<&Rotation2D, With<Children>>,
-    all_parent_positions: Query<&Position2D, With<Children>>,
-    all_parent_scales: Query<&Scale2D, With<Children>>,
-) {
-    query.par_iter_mut().for_each(
-        |(mut transform, position, rotation, scale, draw_order, r_prop, p_prop, s_prop, parent)| {
-            let mut new_rot = Quat::from(rotation);
-            let mut new_pos = Vec3::new(position.x, position.y, draw_order.into());
-            let mut new_scale = Vec3::new(scale.x, scale.y, 1.0);
+    ),
+                  parent: Option<Entity>,
+                  snap: Option<&SnapToGrid>| {
+        // Only absolute propagation needs to know where the parent ended up
+        let absolute = *r_prop == RotationPropagation::Absolute
+            || *p_prop == PositionPropagation::Absolute
+            || *s_prop == ScalePropagation::Absolute;
+        let parent_world = match parent {
+            Some(parent) if absolute => spatial.world_affine(parent),
+            _ => None,
+        };
+        let mut transform = local_transform(
+            (position, rotation, scale, draw_order),
+            (r_prop, p_prop, s_prop),
+            parent_world,
+        );
 
-            if let Some(parent) = parent {
-                let parent_rotation = all_parent_rotations.get(parent.get()).unwrap();
-                let parent_position = all_parent_positions.get(parent.get()).unwrap();
-                let parent_scale = all_parent_scales.get(parent.get()).unwrap();
+        if let Some(snap) = snap {
+            // A grid on the parent is in the same space as our translation. The global one is
+            // world space, which only matches our translation when there is no parent.
+            let grid = match parent {
+                Some(parent) => all_parent_grids.get(parent).ok(),
+                None => grid.as_deref(),
+            };
+            if let Some(grid) = grid {
+                let snapped = grid.snap(transform.translation.truncate().into(), snap.anchor);
+                transform.translation.x = snapped.x;
+                transform.translation.y = snapped.y;
+            }
+        }
+        transform
+    };
 
-                if r_prop == &RotationPropagation::Absolute {
-                    // Counteract parent's rotation effects on position
-                    let temp = position.rotate_radians(-parent_rotation.radians());
-                    new_pos.x = temp.x;
-                    new_pos.y = temp.y;
-                    // Counteract parent's rotation
-                    new_rot = Quat::from(-parent_rotation);
-                }
+    // Projected hierarchies go top down once, carrying each parent's ground and screen space
+    // affines so every entity is projected from its world ground position. Flat ones, including
+    // a root overriding the resource with one, are left to the parallel pass below.
+    projected.clear();
+    for (root, own_projection) in roots.iter() {
+        let Some(projection) = own_projection
+            .or(projection.as_deref())
+            .filter(|projection| !projection.is_flat())
+        else {
+            continue;
+        };
+        let mut stack: Vec<(Entity, Option<(Entity, Affine2, Affine2, f32)>)> = vec![(root, None)];
+        while let Some((entity, parent)) = stack.pop() {
+            let Ok((_, mut transform, item, _, snap, elevation)) = query.get_mut(entity) else {
+                continue;
+            };
+            let local = ground(item, parent.map(|(parent, ..)| parent), snap);
+            let (parent_ground, parent_screen, parent_elevation) = parent.map_or(
+                (Affine2::IDENTITY, Affine2::IDENTITY, 0.0),
+                |(_, ground, screen, elevation)| (ground, screen, elevation),
+            );
+            let world_ground = parent_ground * transform_affine(&local);
+            let elevation = parent_elevation + elevation.map_or(0.0, |elevation| elevation.0);
+            let screen = projection.project_elevated(world_ground.translation.into(), elevation);
+            // Rotation and scale are left alone, only translations move onto the screen
+            let mut translation = local.translation;
+            if parent_screen.matrix2.determinant() != 0.0 {
+                translation = parent_screen
+                    .inverse()
+                    .transform_point2(screen)
+                    .extend(translation.z);
+            }
+            // z adds up down the hierarchy, so children only add the depth their parent lacks
+            translation.z += projection.depth(world_ground.translation.into())
+                - projection.depth(parent_ground.translation.into());
+            transform.set_if_neq(Transform {
+                translation,
+                ..local
+            });
+            projected.insert(entity);
 
-                if p_prop == &PositionPropagation::Absolute {
-                    // Counteract parent's position
-                    new_pos.x = -parent_position.x;
-                    new_pos.y = -parent_position.y;
-                }
+            let screen = Affine2::from_mat2_translation(world_ground.matrix2, screen);
+            if let Ok(children) = all_children.get(entity) {
+                stack.extend(
+                    children
+                        .iter()
+                        .map(|child| (*child, Some((entity, world_ground, screen, elevation)))),
+                );
+            }
+        }
+    }
 
-                if s_prop == &ScalePropagation::Absolute {
-                    // Counteract parent's scale
-                    new_scale.x = 1.0 / parent_scale.x;
-                    new_scale.y = 1.0 / parent_scale.y;
-                }
+    query
+        .par_iter_mut()
+        .for_each(|(entity, mut transform, item, parent, snap, _)| {
+            if projected.contains(&entity) {
+                return;
             }
+            // Only touch the transform when something moved so change detection stays useful
+            transform.set_if_neq(ground(item, parent.map(Parent::get), snap));
+        })
+}
+
+fn transform_affine(transform: &Transform) -> Affine2 {
+    let rotation = Mat3::from_quat(transform.rotation);
+    Affine2::from_mat2_translation(
+        Mat2::from_cols(
+            rotation.x_axis.truncate() * transform.scale.x,
+            rotation.y_axis.truncate() * transform.scale.y,
+        ),
+        transform.translation.truncate(),
+    )
+}
+
+// The transform propagate_spatial2d writes before snapping and projection. Absolute
+// values are world space, so they need the parent's world affine. Transform can't shear,
+// so absolute rotation and scale under rotated non-uniformly scaled ancestors are approximate.
+// A parent scaled to zero can't be undone, its children collapse with it as if relative.
+pub(crate) fn local_transform(
+    (position, rotation, scale, draw_order): (&Position2D, &Rotation2D, &Scale2D, &DrawOrder),
+    (r_prop, p_prop, s_prop): (
+        &RotationPropagation,
+        &PositionPropagation,
+        &ScalePropagation,
+    ),
+    parent_world: Option<Affine2>,
+) -> Transform {
+    let mut new_rot = Quat::from(rotation);
+    let mut new_pos = Vec3::new(position.x, position.y, draw_order.into());
+    let mut new_scale = Vec3::new(scale.x, scale.y, 1.0);
+
+    let parent_world = parent_world.filter(|parent| parent.matrix2.determinant() != 0.0);
+    if let Some(parent_world) = parent_world {
+        let local_from_parent = parent_world.inverse();
+        if r_prop == &RotationPropagation::Absolute || s_prop == &ScalePropagation::Absolute {
+            let (parent_scale, parent_angle) = scale_angle(parent_world.matrix2);
+            // A mirrored parent turns its relative children the other way, same as composing would
+            let turn = parent_world.matrix2.determinant().signum();
+            let angle = match r_prop {
+                RotationPropagation::Relative => parent_angle + turn * rotation.radians().to_f32(),
+                RotationPropagation::Absolute => rotation.radians().to_f32(),
+            };
+            let scale = match s_prop {
+                ScalePropagation::Relative => parent_scale * Vec2::from(*scale),
+                ScalePropagation::Absolute => Vec2::from(*scale),
+            };
+            let (local_scale, local_angle) =
+                scale_angle(local_from_parent.matrix2 * Mat2::from_scale_angle(scale, angle));
+            new_rot = Quat::from_rotation_z(local_angle);
+            new_scale = local_scale.extend(1.0);
+        }
+
+        if p_prop == &PositionPropagation::Absolute {
+            let local = local_from_parent.transform_point2((*position).into());
+            new_pos.x = local.x;
+            new_pos.y = local.y;
+        }
+    }
+
+    Transform {
+        translation: new_pos,
+        rotation: new_rot,
+        scale: new_scale,
+    }
+}
 
-            transform.rotation = new_rot;
-            transform.translation = new_pos;
-            transform.scale = new_scale;
-        },
+// Splits a 2D linear map into scale and angle, keeping any mirroring on the y axis
+fn scale_angle(matrix: Mat2) -> (Vec2, f32) {
+    let x = matrix.x_axis.length();
+    if x == 0.0 {
+        return (Vec2::new(0.0, matrix.y_axis.length()), 0.0);
+    }
+    (
+        Vec2::new(x, matrix.determinant() / x),
+        matrix.x_axis.to_angle(),
     )
 }
 
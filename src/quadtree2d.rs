@@ -0,0 +1,424 @@
+use crate::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+#[derive(Resource, Clone, Debug)]
+pub struct Quadtree2D {
+    max_depth: usize,
+    node_capacity: usize,
+    nodes: Vec<QuadNode>,
+    // First indices of sibling blocks that were merged back into their parent
+    free: Vec<usize>,
+    entries: HashMap<Entity, (usize, Rect)>,
+}
+
+#[derive(Clone, Debug)]
+struct QuadNode {
+    rect: Rect,
+    depth: usize,
+    parent: Option<usize>,
+    children: Option<[usize; 4]>,
+    entities: Vec<Entity>,
+}
+
+impl QuadNode {
+    fn new(rect: Rect, depth: usize, parent: Option<usize>) -> Self {
+        Self {
+            rect,
+            depth,
+            parent,
+            children: None,
+            entities: Vec::new(),
+        }
+    }
+
+    // Loose bounds are twice the size of the node, so anything centred in the node
+    // and no larger than it fits without straddling
+    fn loose(&self) -> Rect {
+        self.rect.inflate(self.rect.half_size().max_element())
+    }
+
+    fn quadrant(&self, point: Vec2) -> usize {
+        let center = self.rect.center();
+        (point.x >= center.x) as usize + 2 * (point.y >= center.y) as usize
+    }
+}
+
+impl Quadtree2D {
+    pub fn new(bounds: Rect) -> Self {
+        Self::with_limits(bounds, 8, 8)
+    }
+
+    pub fn with_limits(bounds: Rect, max_depth: usize, node_capacity: usize) -> Self {
+        assert!(!bounds.is_empty());
+        assert!(node_capacity > 0);
+        Self {
+            max_depth,
+            node_capacity,
+            nodes: vec![QuadNode::new(bounds, 0, None)],
+            free: Vec::new(),
+            entries: HashMap::default(),
+        }
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.nodes[0].rect
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len() - self.free.len() * 4
+    }
+
+    pub fn entity_bounds(&self, entity: Entity) -> Option<Rect> {
+        self.entries.get(&entity).map(|(_, rect)| *rect)
+    }
+
+    fn fits(child: &QuadNode, rect: Rect) -> bool {
+        let half = rect.half_size();
+        let child_half = child.rect.half_size();
+        half.x <= child_half.x && half.y <= child_half.y
+    }
+
+    fn place(&mut self, entity: Entity, rect: Rect) -> usize {
+        let center = rect.center();
+        let mut index = 0;
+        // Anything outside the root stays on the root, queries always look there
+        if !self.nodes[0].rect.contains(center) {
+            self.nodes[0].entities.push(entity);
+            return 0;
+        }
+        loop {
+            if self.nodes[index].children.is_none()
+                && self.nodes[index].entities.len() >= self.node_capacity
+                && self.nodes[index].depth < self.max_depth
+            {
+                self.split(index);
+            }
+            let Some(children) = self.nodes[index].children else {
+                break;
+            };
+            let child = children[self.nodes[index].quadrant(center)];
+            if !Self::fits(&self.nodes[child], rect) {
+                break;
+            }
+            index = child;
+        }
+        self.nodes[index].entities.push(entity);
+        index
+    }
+
+    fn split(&mut self, index: usize) {
+        let rect = self.nodes[index].rect;
+        let depth = self.nodes[index].depth + 1;
+        let center = rect.center();
+        let parent = Some(index);
+        let children = [
+            QuadNode::new(Rect::from_corners(rect.min, center), depth, parent),
+            QuadNode::new(
+                Rect::new(center.x, rect.min.y, rect.max.x, center.y),
+                depth,
+                parent,
+            ),
+            QuadNode::new(
+                Rect::new(rect.min.x, center.y, center.x, rect.max.y),
+                depth,
+                parent,
+            ),
+            QuadNode::new(Rect::from_corners(center, rect.max), depth, parent),
+        ];
+        let first = match self.free.pop() {
+            Some(first) => {
+                for (slot, child) in self.nodes[first..first + 4].iter_mut().zip(children) {
+                    *slot = child;
+                }
+                first
+            }
+            None => {
+                self.nodes.extend(children);
+                self.nodes.len() - 4
+            }
+        };
+        self.nodes[index].children = Some([first, first + 1, first + 2, first + 3]);
+
+        for entity in std::mem::take(&mut self.nodes[index].entities) {
+            let rect = self.entries[&entity].1;
+            let child = first + self.nodes[index].quadrant(rect.center());
+            if self.nodes[index].rect.contains(rect.center())
+                && Self::fits(&self.nodes[child], rect)
+            {
+                self.nodes[child].entities.push(entity);
+                self.entries.get_mut(&entity).unwrap().0 = child;
+            } else {
+                self.nodes[index].entities.push(entity);
+            }
+        }
+    }
+
+    fn detach(&mut self, node: usize, entity: Entity) {
+        let entities = &mut self.nodes[node].entities;
+        if let Some(index) = entities.iter().position(|e| *e == entity) {
+            entities.swap_remove(index);
+        }
+        self.collapse(node);
+    }
+
+    // Merges children that emptied out back into their parent, then tries the next level
+    // up, so entities moving around don't leave a trail of empty nodes behind
+    fn collapse(&mut self, mut index: usize) {
+        loop {
+            if let Some(children) = self.nodes[index].children {
+                let empty = |child: &usize| {
+                    let child = &self.nodes[*child];
+                    child.children.is_none() && child.entities.is_empty()
+                };
+                if !children.iter().all(empty) {
+                    return;
+                }
+                self.nodes[index].children = None;
+                self.free.push(children[0]);
+            }
+            let Some(parent) = self.nodes[index].parent else {
+                return;
+            };
+            index = parent;
+        }
+    }
+
+    fn visit(&self, mut enter: impl FnMut(Rect) -> bool, mut f: impl FnMut(Entity, Rect)) {
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            // The root also holds everything that fell outside of it
+            if index != 0 && !enter(node.loose()) {
+                continue;
+            }
+            for entity in &node.entities {
+                f(*entity, self.entries[entity].1);
+            }
+            if let Some(children) = node.children {
+                stack.extend(children);
+            }
+        }
+    }
+
+    pub fn raycast_by(
+        &self,
+        origin: Position2D,
+        direction: Vec2,
+        max_distance: f32,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<(Entity, f32)> {
+        let origin = Vec2::from(origin);
+        let direction = direction.normalize_or_zero();
+        if direction == Vec2::ZERO {
+            return Vec::new();
+        }
+        let mut hits = Vec::new();
+        self.visit(
+            |rect| ray_rect_distance(origin, direction, rect).is_some_and(|d| d <= max_distance),
+            |entity, rect| {
+                if let Some(distance) = ray_rect_distance(origin, direction, rect) {
+                    if distance <= max_distance && filter(entity) {
+                        hits.push((entity, distance));
+                    }
+                }
+            },
+        );
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+        hits
+    }
+
+    pub fn raycast(
+        &self,
+        origin: Position2D,
+        direction: Vec2,
+        max_distance: f32,
+    ) -> Vec<(Entity, f32)> {
+        self.raycast_by(origin, direction, max_distance, |_| true)
+    }
+}
+
+impl SpatialIndex2D for Quadtree2D {
+    fn insert(&mut self, entity: Entity, bounds: Rect) {
+        if let Some((node, _)) = self.entries.get(&entity).copied() {
+            self.detach(node, entity);
+        }
+        self.entries.insert(entity, (0, bounds));
+        let node = self.place(entity, bounds);
+        self.entries.get_mut(&entity).unwrap().0 = node;
+    }
+
+    fn remove(&mut self, entity: Entity) -> bool {
+        let Some((node, _)) = self.entries.remove(&entity) else {
+            return false;
+        };
+        self.detach(node, entity);
+        true
+    }
+
+    fn contains(&self, entity: Entity) -> bool {
+        self.entries.contains_key(&entity)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn clear(&mut self) {
+        let bounds = self.bounds();
+        self.nodes = vec![QuadNode::new(bounds, 0, None)];
+        self.free.clear();
+        self.entries.clear();
+    }
+
+    fn within_radius_by(
+        &self,
+        center: Position2D,
+        radius: f32,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity> {
+        let center = Vec2::from(center);
+        let query = Rect::from_center_half_size(center, Vec2::splat(radius));
+        let radius_squared = radius * radius;
+        let mut found = Vec::new();
+        self.visit(
+            |rect| overlaps(rect, query),
+            |entity, rect| {
+                if rect_distance_squared(rect, center) <= radius_squared && filter(entity) {
+                    found.push(entity);
+                }
+            },
+        );
+        found
+    }
+
+    fn within_rect_by(&self, query: Rect, filter: impl Fn(Entity) -> bool) -> Vec<Entity> {
+        let mut found = Vec::new();
+        self.visit(
+            |rect| overlaps(rect, query),
+            |entity, rect| {
+                if overlaps(rect, query) && filter(entity) {
+                    found.push(entity);
+                }
+            },
+        );
+        found
+    }
+
+    fn k_nearest_by(
+        &self,
+        center: Position2D,
+        k: usize,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity> {
+        let center = Vec2::from(center);
+        let mut found = Vec::new();
+        if k == 0 {
+            return found;
+        }
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Nearest {
+            distance: 0.0,
+            item: NearestItem::Node(0),
+        });
+        while let Some(Nearest { item, .. }) = heap.pop() {
+            match item {
+                NearestItem::Entity(entity) => {
+                    found.push(entity);
+                    if found.len() == k {
+                        break;
+                    }
+                }
+                NearestItem::Node(index) => {
+                    let node = &self.nodes[index];
+                    for entity in &node.entities {
+                        if filter(*entity) {
+                            heap.push(Nearest {
+                                distance: rect_distance_squared(self.entries[entity].1, center),
+                                item: NearestItem::Entity(*entity),
+                            });
+                        }
+                    }
+                    for child in node.children.into_iter().flatten() {
+                        heap.push(Nearest {
+                            distance: rect_distance_squared(self.nodes[child].loose(), center),
+                            item: NearestItem::Node(child),
+                        });
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for Quadtree2D {
+        fn default() -> Self {
+            Self::new(Rect::from_center_half_size(Vec2::ZERO, Vec2::splat(4096.0)))
+        }
+    }
+}
+
+enum NearestItem {
+    Node(usize),
+    Entity(Entity),
+}
+
+// Min-heap ordering on distance, entities win ties so they are emitted as soon as possible
+struct Nearest {
+    distance: f32,
+    item: NearestItem,
+}
+
+impl Nearest {
+    fn rank(&self) -> u8 {
+        match self.item {
+            NearestItem::Entity(_) => 1,
+            NearestItem::Node(_) => 0,
+        }
+    }
+}
+
+impl PartialEq for Nearest {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Nearest {}
+
+impl PartialOrd for Nearest {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Nearest {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then(self.rank().cmp(&other.rank()))
+    }
+}
+
+// Touching counts, an empty intersection would miss zero sized points
+fn overlaps(a: Rect, b: Rect) -> bool {
+    a.min.cmple(b.max).all() && a.max.cmpge(b.min).all()
+}
+
+fn rect_distance_squared(rect: Rect, point: Vec2) -> f32 {
+    point.clamp(rect.min, rect.max).distance_squared(point)
+}
+
+fn ray_rect_distance(origin: Vec2, direction: Vec2, rect: Rect) -> Option<f32> {
+    let inverse = direction.recip();
+    let t0 = (rect.min - origin) * inverse;
+    let t1 = (rect.max - origin) * inverse;
+    let near = t0.min(t1).max_element().max(0.0);
+    let far = t0.max(t1).min_element();
+    (near <= far).then_some(near)
+}
@@ -3,6 +3,8 @@ use bevy::prelude::*;
 
 #[derive(Default, Clone, Copy, PartialEq, Debug, Reflect, Deref, DerefMut)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct Radians(f32);
 
 impl Radians {
@@ -22,6 +24,22 @@ impl Radians {
         self.0.to_degrees()
     }
 
+    // Wraps into (-PI, PI]
+    pub fn wrapped(self) -> Self {
+        let wrapped = (self.0 + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU)
+            - std::f32::consts::PI;
+        if wrapped == -std::f32::consts::PI {
+            Self(std::f32::consts::PI)
+        } else {
+            Self(wrapped)
+        }
+    }
+
+    // Signed shortest arc from self to target
+    pub fn shortest_to(self, target: impl Into<Radians>) -> Self {
+        Self(target.into().0 - self.0).wrapped()
+    }
+
     pub const ZERO: Self = Self(0.);
     pub const UP: Self = Self(std::f32::consts::FRAC_PI_2);
     pub const DOWN: Self = Self(-std::f32::consts::FRAC_PI_2);
@@ -0,0 +1,151 @@
+use crate::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+pub struct RayHit2D {
+    pub entity: Entity,
+    pub point: Position2D,
+    pub normal: Vec2,
+    pub distance: f32,
+}
+
+// Only entities the spatial index would hold are hit, so adding Quadtree2D doesn't change results
+#[derive(SystemParam)]
+pub struct Raycast2D<'w, 's> {
+    targets: Query<
+        'w,
+        's,
+        (Entity, &'static WorldBounds2D, Option<&'static Layers2D>),
+        With<Position2D>,
+    >,
+    quadtree: Option<Res<'w, Quadtree2D>>,
+}
+
+impl<'w, 's> Raycast2D<'w, 's> {
+    pub fn all_hits_by(
+        &self,
+        origin: Position2D,
+        direction: impl Into<Rotation2D>,
+        max_distance: f32,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<RayHit2D> {
+        let origin = Vec2::from(origin);
+        let direction = Vec2::from(direction.into().radians());
+        let mut hits = Vec::new();
+        let mut test = |entity: Entity, bounds: &WorldBounds2D| {
+            if let Some((distance, normal)) = bounds.ray_intersection(origin, direction) {
+                if distance <= max_distance && filter(entity) {
+                    hits.push(RayHit2D {
+                        entity,
+                        point: (origin + direction * distance).into(),
+                        normal,
+                        distance,
+                    });
+                }
+            }
+        };
+
+        // The quadtree holds world AABBs, so it can narrow things down when present
+        match &self.quadtree {
+            Some(quadtree) => {
+                for (entity, _) in quadtree.raycast(origin.into(), direction, max_distance) {
+                    if let Ok((_, bounds, _)) = self.targets.get(entity) {
+                        test(entity, bounds);
+                    }
+                }
+            }
+            None => {
+                for (entity, bounds, _) in self.targets.iter() {
+                    test(entity, bounds);
+                }
+            }
+        }
+
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        hits
+    }
+
+    pub fn all_hits(
+        &self,
+        origin: Position2D,
+        direction: impl Into<Rotation2D>,
+        max_distance: f32,
+    ) -> Vec<RayHit2D> {
+        self.all_hits_by(origin, direction, max_distance, |_| true)
+    }
+
+    pub fn all_hits_in(
+        &self,
+        origin: Position2D,
+        direction: impl Into<Rotation2D>,
+        max_distance: f32,
+        layers: Layers2D,
+    ) -> Vec<RayHit2D> {
+        self.all_hits_by(origin, direction, max_distance, |entity| {
+            self.in_layers(entity, layers)
+        })
+    }
+
+    pub fn first_hit_by(
+        &self,
+        origin: Position2D,
+        direction: impl Into<Rotation2D>,
+        max_distance: f32,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Option<RayHit2D> {
+        self.all_hits_by(origin, direction, max_distance, filter)
+            .first()
+            .copied()
+    }
+
+    pub fn first_hit(
+        &self,
+        origin: Position2D,
+        direction: impl Into<Rotation2D>,
+        max_distance: f32,
+    ) -> Option<RayHit2D> {
+        self.first_hit_by(origin, direction, max_distance, |_| true)
+    }
+
+    pub fn first_hit_in(
+        &self,
+        origin: Position2D,
+        direction: impl Into<Rotation2D>,
+        max_distance: f32,
+        layers: Layers2D,
+    ) -> Option<RayHit2D> {
+        self.first_hit_by(origin, direction, max_distance, |entity| {
+            self.in_layers(entity, layers)
+        })
+    }
+
+    pub fn line_of_sight(
+        &self,
+        from: Position2D,
+        to: Position2D,
+        layers: Layers2D,
+        ignore: &[Entity],
+    ) -> bool {
+        let delta = Vec2::from(to - from);
+        let distance = delta.length();
+        if distance == 0.0 {
+            return true;
+        }
+        self.first_hit_by(
+            from,
+            Rotation2D::from_f32_radians(delta.to_angle()),
+            distance,
+            |entity| !ignore.contains(&entity) && self.in_layers(entity, layers),
+        )
+        .is_none()
+    }
+
+    fn in_layers(&self, entity: Entity, layers: Layers2D) -> bool {
+        self.targets.get(entity).is_ok_and(|(_, _, entity_layers)| {
+            entity_layers
+                .copied()
+                .unwrap_or_default()
+                .intersects(layers)
+        })
+    }
+}
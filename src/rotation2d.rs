@@ -1,14 +1,18 @@
 use crate::prelude::*;
 use bevy::prelude::*;
 
+// Serializes as a plain angle in degrees, see the serialize module below
 #[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct Rotation2D {
     rot: Rot2,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Component, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub enum RotationPropagation {
     #[default]
     Relative,
@@ -41,6 +45,24 @@ impl Rotation2D {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::Rotation2D;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Serialize for Rotation2D {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_f32(self.degrees().to_f32())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Rotation2D {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            f32::deserialize(deserializer).map(Self::from_f32_degrees)
+        }
+    }
+}
+
 mod from {
     use super::{Compass, CompassHalfwinds, CompassRose, Degrees, Radians, Rotation2D};
 
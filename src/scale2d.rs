@@ -1,7 +1,11 @@
 use bevy::prelude::*;
 
+// A missing axis deserializes as 1 from Default rather than 0
 #[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct Scale2D {
     pub x: f32,
     pub y: f32,
@@ -9,6 +13,8 @@ pub struct Scale2D {
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Component, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub enum ScalePropagation {
     #[default]
     Relative,
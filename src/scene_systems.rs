@@ -0,0 +1,67 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+// Scenes only carry what was saved, or what was written by hand, so fill in whatever
+// the rest of the 2D pipeline needs. Transform is rebuilt by propagate_spatial2d later
+// the same frame, anything stale that came with the scene gets overwritten.
+pub fn complete_spatial2d(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            Has<Rotation2D>,
+            Has<Scale2D>,
+            Has<DrawOrder>,
+            Has<RotationPropagation>,
+            Has<PositionPropagation>,
+            Has<ScalePropagation>,
+            Has<Transform>,
+            Has<GlobalTransform>,
+            Has<Visibility>,
+        ),
+        Added<Position2D>,
+    >,
+) {
+    for (
+        entity,
+        rotation,
+        scale,
+        draw_order,
+        r_prop,
+        p_prop,
+        s_prop,
+        transform,
+        global_transform,
+        visibility,
+    ) in query.iter()
+    {
+        let mut entity = commands.entity(entity);
+        if !rotation {
+            entity.insert(Rotation2D::default());
+        }
+        if !scale {
+            entity.insert(Scale2D::default());
+        }
+        if !draw_order {
+            entity.insert(DrawOrder::default());
+        }
+        if !r_prop {
+            entity.insert(RotationPropagation::default());
+        }
+        if !p_prop {
+            entity.insert(PositionPropagation::default());
+        }
+        if !s_prop {
+            entity.insert(ScalePropagation::default());
+        }
+        if !transform {
+            entity.insert(Transform::default());
+        }
+        if !global_transform {
+            entity.insert(GlobalTransform::default());
+        }
+        if !visibility {
+            entity.insert(VisibilityBundle::default());
+        }
+    }
+}
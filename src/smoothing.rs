@@ -0,0 +1,250 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use std::ops::{Add, Mul, Sub};
+
+pub trait Smoothable:
+    Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+    fn dot(self, other: Self) -> f32;
+}
+
+impl Smoothable for f32 {
+    fn dot(self, other: Self) -> f32 {
+        self * other
+    }
+}
+
+impl Smoothable for Vec2 {
+    fn dot(self, other: Self) -> f32 {
+        Vec2::dot(self, other)
+    }
+}
+
+// Critically damped spring that reaches the target in roughly `smooth_time` seconds
+// without overshooting, `velocity` has to be kept between calls
+pub fn smooth_damp<T: Smoothable>(
+    current: T,
+    target: T,
+    velocity: &mut T,
+    smooth_time: f32,
+    delta: f32,
+) -> T {
+    let smooth_time = smooth_time.max(1e-4);
+    let omega = 2.0 / smooth_time;
+    let x = omega * delta;
+    // Cheap and accurate approximation of exp(-x)
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + change * omega) * delta;
+    *velocity = (*velocity - temp * omega) * decay;
+    let mut output = target + (change + temp) * decay;
+
+    // Landing past the target would mean overshooting
+    if (target - current).dot(output - target) > 0.0 {
+        output = target;
+        *velocity = T::default();
+    }
+    output
+}
+
+// Frame rate independent lerp, `rate` is how quickly the gap closes per second
+pub fn exp_decay<T: Smoothable>(current: T, target: T, rate: f32, delta: f32) -> T {
+    target + (current - target) * (-rate * delta).exp()
+}
+
+// Smooths along the shortest arc, the result is unwrapped so it stays continuous
+pub fn smooth_damp_angle(
+    current: Radians,
+    target: Radians,
+    velocity: &mut f32,
+    smooth_time: f32,
+    delta: f32,
+) -> Radians {
+    let target = current + current.shortest_to(target);
+    Radians::from_f32(smooth_damp(
+        current.to_f32(),
+        target.to_f32(),
+        velocity,
+        smooth_time,
+        delta,
+    ))
+}
+
+pub fn smooth_damp_degrees(
+    current: Degrees,
+    target: Degrees,
+    velocity: &mut f32,
+    smooth_time: f32,
+    delta: f32,
+) -> Degrees {
+    let mut radians_velocity = velocity.to_radians();
+    let result = smooth_damp_angle(
+        Radians::from_f32(current.to_radians_f32()),
+        Radians::from_f32(target.to_radians_f32()),
+        &mut radians_velocity,
+        smooth_time,
+        delta,
+    );
+    *velocity = radians_velocity.to_degrees();
+    Degrees::from_f32(result.to_degrees_f32())
+}
+
+pub fn exp_decay_angle(current: Radians, target: Radians, rate: f32, delta: f32) -> Radians {
+    let target = current + current.shortest_to(target);
+    Radians::from_f32(exp_decay(current.to_f32(), target.to_f32(), rate, delta))
+}
+
+pub fn exp_decay_degrees(current: Degrees, target: Degrees, rate: f32, delta: f32) -> Degrees {
+    let result = exp_decay_angle(
+        Radians::from_f32(current.to_radians_f32()),
+        Radians::from_f32(target.to_radians_f32()),
+        rate,
+        delta,
+    );
+    Degrees::from_f32(result.to_degrees_f32())
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum SmoothMode2D {
+    SmoothDamp { smooth_time: f32 },
+    ExpDecay { rate: f32 },
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct SmoothPosition2D {
+    pub target: Position2D,
+    pub mode: SmoothMode2D,
+    velocity: Vec2,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct SmoothRotation2D {
+    pub target: Rotation2D,
+    pub mode: SmoothMode2D,
+    velocity: f32,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct SmoothScale2D {
+    pub target: Scale2D,
+    pub mode: SmoothMode2D,
+    velocity: Vec2,
+}
+
+impl SmoothMode2D {
+    fn step<T: Smoothable>(&self, current: T, target: T, velocity: &mut T, delta: f32) -> T {
+        match *self {
+            Self::SmoothDamp { smooth_time } => {
+                smooth_damp(current, target, velocity, smooth_time, delta)
+            }
+            Self::ExpDecay { rate } => exp_decay(current, target, rate, delta),
+        }
+    }
+}
+
+impl SmoothPosition2D {
+    pub fn new(target: Position2D, mode: SmoothMode2D) -> Self {
+        Self {
+            target,
+            mode,
+            velocity: Vec2::ZERO,
+        }
+    }
+
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    pub fn step(&mut self, current: Position2D, delta: f32) -> Position2D {
+        let mut velocity = self.velocity;
+        let next = self
+            .mode
+            .step(current.into(), self.target.into(), &mut velocity, delta);
+        self.velocity = velocity;
+        next.into()
+    }
+}
+
+impl SmoothRotation2D {
+    pub fn new(target: impl Into<Rotation2D>, mode: SmoothMode2D) -> Self {
+        Self {
+            target: target.into(),
+            mode,
+            velocity: 0.0,
+        }
+    }
+
+    pub fn velocity(&self) -> Radians {
+        Radians::from_f32(self.velocity)
+    }
+
+    pub fn step(&mut self, current: Rotation2D, delta: f32) -> Rotation2D {
+        let current = current.radians();
+        let target = self.target.radians();
+        let next = match self.mode {
+            SmoothMode2D::SmoothDamp { smooth_time } => {
+                smooth_damp_angle(current, target, &mut self.velocity, smooth_time, delta)
+            }
+            SmoothMode2D::ExpDecay { rate } => exp_decay_angle(current, target, rate, delta),
+        };
+        next.into()
+    }
+}
+
+impl SmoothScale2D {
+    pub fn new(target: Scale2D, mode: SmoothMode2D) -> Self {
+        Self {
+            target,
+            mode,
+            velocity: Vec2::ZERO,
+        }
+    }
+
+    pub fn step(&mut self, current: Scale2D, delta: f32) -> Vec2 {
+        let mut velocity = self.velocity;
+        let next = self
+            .mode
+            .step(current.into(), self.target.into(), &mut velocity, delta);
+        self.velocity = velocity;
+        next
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for SmoothMode2D {
+        fn default() -> Self {
+            Self::SmoothDamp { smooth_time: 0.3 }
+        }
+    }
+
+    impl Default for SmoothPosition2D {
+        fn default() -> Self {
+            Self::new(Position2D::default(), SmoothMode2D::default())
+        }
+    }
+
+    impl Default for SmoothRotation2D {
+        fn default() -> Self {
+            Self::new(Rotation2D::default(), SmoothMode2D::default())
+        }
+    }
+
+    impl Default for SmoothScale2D {
+        fn default() -> Self {
+            Self::new(Scale2D::default(), SmoothMode2D::default())
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use crate::prelude::*;
+use bevy::{math::I64Vec2, prelude::*, utils::HashMap};
+
+#[derive(Resource, Clone, Debug)]
+pub struct SpatialGrid2D {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<Entity>>,
+    entries: HashMap<Entity, (IVec2, Position2D)>,
+    min_cell: IVec2,
+    max_cell: IVec2,
+}
+
+impl SpatialGrid2D {
+    pub fn new(cell_size: f32) -> Self {
+        assert!(cell_size > 0.0);
+        Self {
+            cell_size,
+            cells: HashMap::default(),
+            entries: HashMap::default(),
+            min_cell: IVec2::MAX,
+            max_cell: IVec2::MIN,
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    pub fn cell_of(&self, position: Position2D) -> IVec2 {
+        (Vec2::from(position) / self.cell_size).floor().as_ivec2()
+    }
+
+    pub fn position(&self, entity: Entity) -> Option<Position2D> {
+        self.entries.get(&entity).map(|(_, position)| *position)
+    }
+
+    // Smallest and largest occupied cells, None when empty
+    pub fn occupied_cells(&self) -> Option<(IVec2, IVec2)> {
+        (!self.cells.is_empty()).then_some((self.min_cell, self.max_cell))
+    }
+
+    fn remove_from_cell(&mut self, cell: IVec2, entity: Entity) {
+        if let Some(bucket) = self.cells.get_mut(&cell) {
+            if let Some(index) = bucket.iter().position(|e| *e == entity) {
+                bucket.swap_remove(index);
+            }
+            if bucket.is_empty() {
+                self.cells.remove(&cell);
+                self.shrink_bounds(cell);
+            }
+        }
+    }
+
+    // Keeps k_nearest from scanning rings that used to be occupied
+    fn shrink_bounds(&mut self, emptied: IVec2) {
+        let on_edge = emptied.x == self.min_cell.x
+            || emptied.y == self.min_cell.y
+            || emptied.x == self.max_cell.x
+            || emptied.y == self.max_cell.y;
+        if !on_edge {
+            return;
+        }
+        self.min_cell = IVec2::MAX;
+        self.max_cell = IVec2::MIN;
+        for cell in self.cells.keys() {
+            self.min_cell = self.min_cell.min(*cell);
+            self.max_cell = self.max_cell.max(*cell);
+        }
+    }
+
+    // Only the outer edge of the square `ring` cells out, clipped to the occupied cells
+    fn for_each_in_ring(&self, origin: I64Vec2, ring: i64, mut f: impl FnMut(Entity, Position2D)) {
+        let (min, max) = (origin - ring, origin + ring);
+        let clipped_min = min.max(self.min_cell.as_i64vec2());
+        let clipped_max = max.min(self.max_cell.as_i64vec2());
+        let mut visit = |x: i64, y: i64| {
+            // Clipped to occupied cells or the origin's own, so these fit back in an i32
+            if let Some(bucket) = self.cells.get(&IVec2::new(x as i32, y as i32)) {
+                for entity in bucket {
+                    f(*entity, self.entries[entity].1);
+                }
+            }
+        };
+        if ring == 0 {
+            visit(origin.x, origin.y);
+            return;
+        }
+        for y in [min.y, max.y] {
+            if (clipped_min.y..=clipped_max.y).contains(&y) {
+                for x in clipped_min.x..=clipped_max.x {
+                    visit(x, y);
+                }
+            }
+        }
+        for x in [min.x, max.x] {
+            if (clipped_min.x..=clipped_max.x).contains(&x) {
+                for y in clipped_min.y.max(min.y + 1)..=clipped_max.y.min(max.y - 1) {
+                    visit(x, y);
+                }
+            }
+        }
+    }
+
+    fn for_each_in_cells(&self, min: IVec2, max: IVec2, mut f: impl FnMut(Entity, Position2D)) {
+        let min = min.max(self.min_cell);
+        let max = max.min(self.max_cell);
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if let Some(bucket) = self.cells.get(&IVec2::new(x, y)) {
+                    for entity in bucket {
+                        f(*entity, self.entries[entity].1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl SpatialIndex2D for SpatialGrid2D {
+    fn insert(&mut self, entity: Entity, bounds: Rect) {
+        let position = Position2D::from(bounds.center());
+        let cell = self.cell_of(position);
+        if let Some((old_cell, old_position)) = self.entries.get_mut(&entity) {
+            *old_position = position;
+            if *old_cell == cell {
+                return;
+            }
+            let old_cell = std::mem::replace(old_cell, cell);
+            self.remove_from_cell(old_cell, entity);
+        } else {
+            self.entries.insert(entity, (cell, position));
+        }
+        self.cells.entry(cell).or_default().push(entity);
+        self.min_cell = self.min_cell.min(cell);
+        self.max_cell = self.max_cell.max(cell);
+    }
+
+    fn remove(&mut self, entity: Entity) -> bool {
+        let Some((cell, _)) = self.entries.remove(&entity) else {
+            return false;
+        };
+        self.remove_from_cell(cell, entity);
+        true
+    }
+
+    fn contains(&self, entity: Entity) -> bool {
+        self.entries.contains_key(&entity)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+        self.min_cell = IVec2::MAX;
+        self.max_cell = IVec2::MIN;
+    }
+
+    fn within_radius_by(
+        &self,
+        center: Position2D,
+        radius: f32,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity> {
+        let center = Vec2::from(center);
+        let min = self.cell_of((center - radius).into());
+        let max = self.cell_of((center + radius).into());
+        let radius_squared = radius * radius;
+        let mut found = Vec::new();
+        self.for_each_in_cells(min, max, |entity, position| {
+            if Vec2::from(position).distance_squared(center) <= radius_squared && filter(entity) {
+                found.push(entity);
+            }
+        });
+        found
+    }
+
+    fn within_rect_by(&self, rect: Rect, filter: impl Fn(Entity) -> bool) -> Vec<Entity> {
+        let min = self.cell_of(rect.min.into());
+        let max = self.cell_of(rect.max.into());
+        let mut found = Vec::new();
+        self.for_each_in_cells(min, max, |entity, position| {
+            if rect.contains(position.into()) && filter(entity) {
+                found.push(entity);
+            }
+        });
+        found
+    }
+
+    fn k_nearest_by(
+        &self,
+        center: Position2D,
+        k: usize,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity> {
+        if k == 0 || self.is_empty() {
+            return Vec::new();
+        }
+
+        // In i64 so rings between far apart cells can't overflow. Rings nearer than the
+        // occupied cells are empty, so the search starts at the first one that reaches them.
+        let origin = self.cell_of(center).as_i64vec2();
+        let center = Vec2::from(center);
+        let (min_cell, max_cell) = (self.min_cell.as_i64vec2(), self.max_cell.as_i64vec2());
+        let first_ring = (min_cell - origin)
+            .max(origin - max_cell)
+            .max(I64Vec2::ZERO)
+            .max_element();
+        let last_ring = (origin - min_cell)
+            .abs()
+            .max((max_cell - origin).abs())
+            .max_element();
+
+        let mut candidates: Vec<(f32, Entity)> = Vec::new();
+        let candidate = |entity: Entity, position: Position2D| {
+            filter(entity).then(|| (Vec2::from(position).distance_squared(center), entity))
+        };
+        for ring in first_ring..=last_ring {
+            // Once a ring has more cells than are occupied, scanning those is cheaper
+            if ring * 8 > self.cells.len() as i64 {
+                for (cell, bucket) in &self.cells {
+                    if (cell.as_i64vec2() - origin).abs().max_element() >= ring {
+                        candidates.extend(
+                            bucket
+                                .iter()
+                                .filter_map(|entity| candidate(*entity, self.entries[entity].1)),
+                        );
+                    }
+                }
+                break;
+            }
+            self.for_each_in_ring(origin, ring, |entity, position| {
+                candidates.extend(candidate(entity, position));
+            });
+
+            if candidates.len() >= k {
+                candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+                candidates.truncate(k);
+                // Anything in an unvisited ring is at least this far away
+                let reach = ring as f32 * self.cell_size;
+                if candidates[k - 1].0 <= reach * reach {
+                    break;
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        candidates.truncate(k);
+        candidates.into_iter().map(|(_, entity)| entity).collect()
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for SpatialGrid2D {
+        fn default() -> Self {
+            Self::new(64.0)
+        }
+    }
+}
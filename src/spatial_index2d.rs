@@ -0,0 +1,79 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::query::{QueryData, QueryFilter},
+    prelude::*,
+};
+
+pub trait SpatialIndex2D: Resource {
+    fn insert(&mut self, entity: Entity, bounds: Rect);
+
+    fn remove(&mut self, entity: Entity) -> bool;
+
+    fn contains(&self, entity: Entity) -> bool;
+
+    fn len(&self) -> usize;
+
+    fn clear(&mut self);
+
+    fn within_radius_by(
+        &self,
+        center: Position2D,
+        radius: f32,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity>;
+
+    fn within_rect_by(&self, rect: Rect, filter: impl Fn(Entity) -> bool) -> Vec<Entity>;
+
+    fn k_nearest_by(
+        &self,
+        center: Position2D,
+        k: usize,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn insert_point(&mut self, entity: Entity, position: Position2D) {
+        self.insert(entity, Rect::from_center_size(position.into(), Vec2::ZERO));
+    }
+
+    fn within_radius(&self, center: Position2D, radius: f32) -> Vec<Entity> {
+        self.within_radius_by(center, radius, |_| true)
+    }
+
+    fn within_radius_filtered<D: QueryData, F: QueryFilter>(
+        &self,
+        center: Position2D,
+        radius: f32,
+        query: &Query<D, F>,
+    ) -> Vec<Entity> {
+        self.within_radius_by(center, radius, |entity| query.contains(entity))
+    }
+
+    fn within_rect(&self, rect: Rect) -> Vec<Entity> {
+        self.within_rect_by(rect, |_| true)
+    }
+
+    fn within_rect_filtered<D: QueryData, F: QueryFilter>(
+        &self,
+        rect: Rect,
+        query: &Query<D, F>,
+    ) -> Vec<Entity> {
+        self.within_rect_by(rect, |entity| query.contains(entity))
+    }
+
+    fn k_nearest(&self, center: Position2D, k: usize) -> Vec<Entity> {
+        self.k_nearest_by(center, k, |_| true)
+    }
+
+    fn k_nearest_filtered<D: QueryData, F: QueryFilter>(
+        &self,
+        center: Position2D,
+        k: usize,
+        query: &Query<D, F>,
+    ) -> Vec<Entity> {
+        self.k_nearest_by(center, k, |entity| query.contains(entity))
+    }
+}
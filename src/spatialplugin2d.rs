@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use bevy::animation::animate_targets;
 use bevy::prelude::*;
 use bevy::transform::TransformSystem;
 
@@ -6,6 +7,20 @@ pub struct SpatialPlugin2D;
 
 impl Plugin for SpatialPlugin2D {
     fn build(&self, app: &mut App) {
+        #[cfg(feature = "tiled")]
+        app.register_type::<TiledMap2D>()
+            .register_type::<TiledLayer2D>()
+            .register_type::<TiledTile2D>()
+            .register_type::<TiledObject2D>();
+
+        #[cfg(feature = "ldtk")]
+        app.register_type::<LdtkLevel2D>()
+            .register_type::<LdtkLayer2D>()
+            .register_type::<LdtkEntity2D>()
+            .register_type::<LdtkIntCell2D>()
+            .register_type::<LdtkTile2D>()
+            .register_type::<LdtkFields2D>();
+
         app.register_type::<DrawOrder>()
             .register_type::<RotationPropagation>()
             .register_type::<PositionPropagation>()
@@ -18,6 +33,91 @@ impl Plugin for SpatialPlugin2D {
             .register_type::<Compass>()
             .register_type::<CompassHalfwinds>()
             .register_type::<CompassRose>()
+            .register_type::<Bounds2D>()
+            .register_type::<WorldBounds2D>()
+            .register_type::<HierarchyBounds2D>()
+            .register_type::<Obb2D>()
+            .register_type::<Layers2D>()
+            .register_type::<GridCoord>()
+            .register_type::<Grid2D>()
+            .register_type::<CellAnchor>()
+            .register_type::<SnapToGrid>()
+            .register_type::<HexCoord>()
+            .register_type::<HexLayout>()
+            .register_type::<HexOrientation>()
+            .register_type::<PointyHexDirection>()
+            .register_type::<FlatHexDirection>()
+            .register_type::<Projection2D>()
+            .register_type::<ProjectionMode2D>()
+            .register_type::<Elevation2D>()
+            .register_type::<LookAt2D>()
+            .register_type::<LookTarget2D>()
+            .register_type::<Follow2D>()
+            .register_type::<FollowOffset2D>()
+            .register_type::<FollowSmoothing2D>()
+            .register_type::<Orbit2D>()
+            .register_type::<OrbitCenter2D>()
+            .register_type::<FollowPath2D>()
+            .register_type::<PathCurve2D>()
+            .register_type::<PathLoop2D>()
+            .register_type::<PathEventKind2D>()
+            .register_type::<ClampPosition2D>()
+            .register_type::<ClampRotation2D>()
+            .register_type::<ClampRegion2D>()
+            .register_type::<ClampSpace2D>()
+            .register_type::<ClampMode2D>()
+            .add_event::<PathEvent2D>()
+            .register_type::<Animated2D>()
+            .register_type::<SmoothPosition2D>()
+            .register_type::<SmoothRotation2D>()
+            .register_type::<SmoothScale2D>()
+            .register_type::<SmoothMode2D>()
+            .register_type::<Interpolation2D>()
+            .configure_sets(
+                PostUpdate,
+                (
+                    SpatialSystems2D::Animation,
+                    SpatialSystems2D::Constraints,
+                    SpatialSystems2D::Propagate,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PostUpdate,
+                complete_spatial2d.before(SpatialSystems2D::Animation),
+            )
+            .add_systems(
+                PostStartup,
+                complete_spatial2d.before(SpatialSystems2D::Propagate),
+            )
+            .add_systems(
+                PostUpdate,
+                sync_animated2d
+                    .after(animate_targets)
+                    .in_set(SpatialSystems2D::Animation),
+            )
+            .add_systems(
+                PostUpdate,
+                record_animated2d
+                    .after(propagate_spatial2d)
+                    .in_set(SpatialSystems2D::Propagate),
+            )
+            .add_systems(
+                PostUpdate,
+                // Motion first, then followers, then facing once everything has settled,
+                // clamps go last so nothing can push an entity back out
+                (
+                    orbit2d,
+                    follow_path2d,
+                    (smooth_position2d, smooth_rotation2d, smooth_scale2d),
+                    follow2d,
+                    look_at2d,
+                    clamp_position2d,
+                    clamp_rotation2d,
+                )
+                    .chain()
+                    .in_set(SpatialSystems2D::Constraints),
+            )
             .add_systems(
                 PostUpdate,
                 (
@@ -39,6 +139,40 @@ impl Plugin for SpatialPlugin2D {
                 )
                     .in_set(SpatialSystems2D::Propagate)
                     .before(TransformSystem::TransformPropagate),
+            )
+            .add_systems(
+                PostUpdate,
+                (update_world_bounds2d, update_hierarchy_bounds2d)
+                    .chain()
+                    .in_set(SpatialSystems2D::Bounds)
+                    .after(TransformSystem::TransformPropagate),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    update_spatial_index2d::<SpatialGrid2D>
+                        .run_if(resource_exists::<SpatialGrid2D>),
+                    update_spatial_index2d::<Quadtree2D>.run_if(resource_exists::<Quadtree2D>),
+                )
+                    .in_set(SpatialSystems2D::Index)
+                    .after(SpatialSystems2D::Bounds),
+            )
+            .add_systems(
+                PostStartup,
+                (update_world_bounds2d, update_hierarchy_bounds2d)
+                    .chain()
+                    .in_set(SpatialSystems2D::Bounds)
+                    .after(TransformSystem::TransformPropagate),
+            )
+            .add_systems(
+                PostStartup,
+                (
+                    update_spatial_index2d::<SpatialGrid2D>
+                        .run_if(resource_exists::<SpatialGrid2D>),
+                    update_spatial_index2d::<Quadtree2D>.run_if(resource_exists::<Quadtree2D>),
+                )
+                    .in_set(SpatialSystems2D::Index)
+                    .after(SpatialSystems2D::Bounds),
             );
     }
 }
@@ -0,0 +1,325 @@
+use crate::prelude::*;
+use bevy::{math::Affine2, prelude::*};
+use std::{fmt::Write, path::Path};
+
+const FILL: &str = "#4a90d9";
+const ARROW: &str = "#d94a4a";
+const BOUNDS: &str = "#3bb273";
+const EDGE: &str = "#999999";
+
+// Debug dump of the propagated 2D world, run it after at least one update. Output only
+// depends on the world so it can be committed and diffed like any other snapshot.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SvgExport2D {
+    // Size of one world unit in the output
+    pub pixels_per_unit: f32,
+    // Arrow length and side of the scale rect, in world units
+    pub marker_size: f32,
+    pub margin: f32,
+    pub bounds: bool,
+    pub hierarchy: bool,
+    pub labels: bool,
+}
+
+struct Node {
+    entity: Entity,
+    path: String,
+    world_from_local: Affine2,
+    depth: f32,
+    parent: Option<Entity>,
+    name: Option<String>,
+    bounds: Option<WorldBounds2D>,
+}
+
+impl SvgExport2D {
+    pub fn with_pixels_per_unit(mut self, pixels_per_unit: f32) -> Self {
+        self.pixels_per_unit = pixels_per_unit;
+        self
+    }
+
+    pub fn with_marker_size(mut self, marker_size: f32) -> Self {
+        self.marker_size = marker_size;
+        self
+    }
+
+    pub fn without_bounds(mut self) -> Self {
+        self.bounds = false;
+        self
+    }
+
+    pub fn without_hierarchy(mut self) -> Self {
+        self.hierarchy = false;
+        self
+    }
+
+    pub fn without_labels(mut self) -> Self {
+        self.labels = false;
+        self
+    }
+
+    pub fn save(&self, world: &mut World, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.render(world))
+    }
+
+    pub fn render(&self, world: &mut World) -> String {
+        let mut nodes: Vec<Node> = world
+            .query_filtered::<(
+                Entity,
+                &GlobalTransform,
+                Option<&Parent>,
+                Option<&Name>,
+                Option<&WorldBounds2D>,
+            ), With<Position2D>>()
+            .iter(world)
+            .map(|(entity, transform, parent, name, bounds)| {
+                let affine = transform.affine();
+                Node {
+                    entity,
+                    path: name_path(world, entity),
+                    world_from_local: Affine2::from_mat2_translation(
+                        Mat2::from_cols(
+                            affine.matrix3.x_axis.truncate(),
+                            affine.matrix3.y_axis.truncate(),
+                        ),
+                        affine.translation.truncate(),
+                    ),
+                    depth: affine.translation.z,
+                    parent: parent.map(Parent::get),
+                    name: name.map(|name| name.to_string()),
+                    bounds: bounds.copied(),
+                }
+            })
+            .collect();
+        // Same order things are drawn in, so later elements sit on top like they would in game.
+        // Ties go by path rather than entity so spawn order doesn't reshuffle the output.
+        nodes.sort_by(|a, b| {
+            a.depth
+                .total_cmp(&b.depth)
+                .then_with(|| a.path.cmp(&b.path))
+                .then(a.entity.cmp(&b.entity))
+        });
+
+        let view = self.view(&nodes);
+        let (min_depth, max_depth) = nodes.iter().fold((f32::MAX, f32::MIN), |(min, max), node| {
+            (min.min(node.depth), max.max(node.depth))
+        });
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#,
+            num(view.min.x),
+            num(-view.max.y),
+            num(view.width()),
+            num(view.height()),
+            num(view.width() * self.pixels_per_unit),
+            num(view.height() * self.pixels_per_unit),
+        );
+        let _ = writeln!(
+            svg,
+            r#"<g fill="none" stroke-width="1" font-family="monospace" font-size="{}">"#,
+            num(self.marker_size * 0.75)
+        );
+
+        if self.hierarchy {
+            svg.push_str("<g id=\"hierarchy\">\n");
+            for node in &nodes {
+                let Some(parent) = node
+                    .parent
+                    .and_then(|parent| nodes.iter().find(|other| other.entity == parent))
+                else {
+                    continue;
+                };
+                let (from, to) = (
+                    parent.world_from_local.translation,
+                    node.world_from_local.translation,
+                );
+                let _ = writeln!(
+                    svg,
+                    r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{EDGE}" stroke-dasharray="4 2" vector-effect="non-scaling-stroke"/>"#,
+                    num(from.x),
+                    num(-from.y),
+                    num(to.x),
+                    num(-to.y),
+                );
+            }
+            svg.push_str("</g>\n");
+        }
+
+        if self.bounds {
+            svg.push_str("<g id=\"bounds\">\n");
+            for bounds in nodes.iter().filter_map(|node| node.bounds.as_ref()) {
+                let transform = svg_matrix(bounds.world_from_local());
+                let _ = match bounds.shape() {
+                    Bounds2D::Rect { half_size, offset } => writeln!(
+                        svg,
+                        r#"<rect x="{}" y="{}" width="{}" height="{}" transform="{transform}" stroke="{BOUNDS}" vector-effect="non-scaling-stroke"/>"#,
+                        num(offset.x - half_size.x),
+                        num(offset.y - half_size.y),
+                        num(half_size.x * 2.0),
+                        num(half_size.y * 2.0),
+                    ),
+                    Bounds2D::Circle { radius, offset } => writeln!(
+                        svg,
+                        r#"<circle cx="{}" cy="{}" r="{}" transform="{transform}" stroke="{BOUNDS}" vector-effect="non-scaling-stroke"/>"#,
+                        num(offset.x),
+                        num(offset.y),
+                        num(radius),
+                    ),
+                };
+            }
+            svg.push_str("</g>\n");
+        }
+
+        svg.push_str("<g id=\"entities\">\n");
+        for node in &nodes {
+            let opacity = if max_depth > min_depth {
+                0.15 + 0.7 * (node.depth - min_depth) / (max_depth - min_depth)
+            } else {
+                0.5
+            };
+            let half = self.marker_size * 0.5;
+            let affine = node.world_from_local;
+            let corners = [
+                Vec2::new(-half, -half),
+                Vec2::new(half, -half),
+                Vec2::new(half, half),
+                Vec2::new(-half, half),
+            ]
+            .map(|corner| affine.transform_point2(corner));
+            let origin = affine.translation;
+            let direction = affine.matrix2.x_axis.normalize_or_zero();
+            let tip = origin + direction * self.marker_size;
+            let head = [
+                tip - direction * half * 0.5 + direction.perp() * half * 0.3,
+                tip,
+                tip - direction * half * 0.5 - direction.perp() * half * 0.3,
+            ];
+
+            let _ = writeln!(svg, r#"<g data-path="{}">"#, escape(&node.path));
+            let _ = writeln!(
+                svg,
+                r#"<polygon points="{}" fill="{FILL}" fill-opacity="{}" stroke="{FILL}" vector-effect="non-scaling-stroke"/>"#,
+                points(&corners),
+                num(opacity),
+            );
+            let _ = writeln!(
+                svg,
+                r#"<polyline points="{}" stroke="{ARROW}" vector-effect="non-scaling-stroke"/>"#,
+                points(&[origin, tip]),
+            );
+            let _ = writeln!(
+                svg,
+                r#"<polyline points="{}" stroke="{ARROW}" vector-effect="non-scaling-stroke"/>"#,
+                points(&head),
+            );
+            if self.labels {
+                if let Some(name) = &node.name {
+                    let _ = writeln!(
+                        svg,
+                        r#"<text x="{}" y="{}" fill="black">{}</text>"#,
+                        num(origin.x + half),
+                        num(-(origin.y + half)),
+                        escape(name),
+                    );
+                }
+            }
+            svg.push_str("</g>\n");
+        }
+        svg.push_str("</g>\n</g>\n</svg>\n");
+        svg
+    }
+
+    // World space rect around everything that gets drawn
+    fn view(&self, nodes: &[Node]) -> Rect {
+        let reach = self.marker_size * std::f32::consts::SQRT_2;
+        let mut view = nodes
+            .iter()
+            .map(|node| {
+                let scale = node.world_from_local.matrix2.x_axis.length()
+                    + node.world_from_local.matrix2.y_axis.length();
+                let marker = Rect::from_center_half_size(
+                    node.world_from_local.translation,
+                    Vec2::splat(reach.max(reach * scale * 0.5)),
+                );
+                match &node.bounds {
+                    Some(bounds) if self.bounds => marker.union(bounds.aabb),
+                    _ => marker,
+                }
+            })
+            .reduce(|a, b| a.union(b))
+            .unwrap_or(Rect::from_center_half_size(Vec2::ZERO, Vec2::ONE));
+        view.min -= Vec2::splat(self.margin);
+        view.max += Vec2::splat(self.margin);
+        view
+    }
+}
+
+// Names from the root down, unnamed entities fall back to #index
+pub(crate) fn name_path(world: &World, entity: Entity) -> String {
+    let mut names = Vec::new();
+    let mut current = Some(entity);
+    while let Some(entity) = current {
+        names.push(
+            world
+                .get::<Name>(entity)
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| format!("#{}", entity.index())),
+        );
+        current = world.get::<Parent>(entity).map(Parent::get);
+    }
+    names.reverse();
+    names.join("/")
+}
+
+// Maps local y-up coordinates straight to SVG's y-down ones
+fn svg_matrix(affine: Affine2) -> String {
+    let flip = Affine2::from_scale(Vec2::new(1.0, -1.0));
+    let m = flip * affine;
+    format!(
+        "matrix({} {} {} {} {} {})",
+        num(m.matrix2.x_axis.x),
+        num(m.matrix2.x_axis.y),
+        num(m.matrix2.y_axis.x),
+        num(m.matrix2.y_axis.y),
+        num(m.translation.x),
+        num(m.translation.y),
+    )
+}
+
+fn points(points: &[Vec2]) -> String {
+    points
+        .iter()
+        .map(|point| format!("{},{}", num(point.x), num(-point.y)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Fixed precision and no negative zero, so unrelated float noise doesn't show up in diffs
+fn num(value: f32) -> String {
+    let value = if value.abs() < 0.005 { 0.0 } else { value };
+    format!("{value:.2}")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+mod default {
+    use super::*;
+    impl Default for SvgExport2D {
+        fn default() -> Self {
+            Self {
+                pixels_per_unit: 1.0,
+                marker_size: 16.0,
+                margin: 16.0,
+                bounds: true,
+                hierarchy: true,
+                labels: true,
+            }
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use crate::prelude::*;
+use crate::svg_export2d::name_path;
+use bevy::{math::Affine2, prelude::*, transform::TransformPlugin};
+use std::{fmt, path::Path};
+
+// Set to regenerate golden files instead of comparing against them
+pub const SNAPSHOT_BLESS_VAR: &str = "SPATIAL2D_BLESS";
+
+// Declarative 2D hierarchy for tests. Rotations are in degrees so they read like the snapshots.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TestNode2D {
+    pub name: String,
+    pub position: Position2D,
+    pub rotation: Rotation2D,
+    pub scale: Scale2D,
+    pub draw_order: DrawOrder,
+    pub rotation_propagation: RotationPropagation,
+    pub position_propagation: PositionPropagation,
+    pub scale_propagation: ScalePropagation,
+    pub children: Vec<TestNode2D>,
+}
+
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct TestHierarchy2D {
+    pub roots: Vec<TestNode2D>,
+}
+
+// World space state of every named spatial entity, keyed by its path of names from the root
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct SpatialSnapshot2D {
+    pub entries: Vec<SnapshotEntry2D>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct SnapshotEntry2D {
+    pub path: String,
+    pub position: Vec2,
+    // Degrees in (-180, 180]
+    pub rotation: f32,
+    // y is the part of the y axis perpendicular to x, so mirroring keeps its sign under shear
+    pub scale: Vec2,
+    pub z: f32,
+}
+
+#[derive(Debug)]
+pub enum SnapshotError2D {
+    Io(std::io::Error),
+    Parse { line: usize, text: String },
+}
+
+impl TestNode2D {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            position: Position2D::zero(),
+            rotation: Rotation2D::new(),
+            scale: Scale2D::default(),
+            draw_order: DrawOrder::default(),
+            rotation_propagation: RotationPropagation::Relative,
+            position_propagation: PositionPropagation::Relative,
+            scale_propagation: ScalePropagation::Relative,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn at(mut self, x: f32, y: f32) -> Self {
+        self.position = Position2D::new(x, y);
+        self
+    }
+
+    pub fn rotated(mut self, degrees: f32) -> Self {
+        self.rotation = Rotation2D::from_f32_degrees(degrees);
+        self
+    }
+
+    pub fn scaled(mut self, x: f32, y: f32) -> Self {
+        self.scale = Scale2D::new(x, y);
+        self
+    }
+
+    pub fn with_draw_order(mut self, order: f32) -> Self {
+        self.draw_order = DrawOrder::new(order);
+        self
+    }
+
+    pub fn absolute_position(mut self) -> Self {
+        self.position_propagation = PositionPropagation::Absolute;
+        self
+    }
+
+    pub fn absolute_rotation(mut self) -> Self {
+        self.rotation_propagation = RotationPropagation::Absolute;
+        self
+    }
+
+    pub fn absolute_scale(mut self) -> Self {
+        self.scale_propagation = ScalePropagation::Absolute;
+        self
+    }
+
+    pub fn with_child(mut self, child: TestNode2D) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn with_children(mut self, children: impl IntoIterator<Item = TestNode2D>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    pub fn spawn(&self, world: &mut World) -> Entity {
+        let entity = world
+            .spawn((
+                SpatialBundle2D {
+                    position: self.position,
+                    rotation: self.rotation,
+                    scale: self.scale,
+                    draw_order: self.draw_order,
+                    r_prop: self.rotation_propagation,
+                    p_prop: self.position_propagation,
+                    s_prop: self.scale_propagation,
+                    ..default()
+                },
+                Name::new(self.name.clone()),
+            ))
+            .id();
+        for child in &self.children {
+            let child = child.spawn(world);
+            world.entity_mut(child).set_parent(entity);
+        }
+        entity
+    }
+}
+
+impl TestHierarchy2D {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_root(mut self, root: TestNode2D) -> Self {
+        self.roots.push(root);
+        self
+    }
+
+    // Just enough of Bevy to propagate, no window or renderer
+    pub fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            HierarchyPlugin,
+            TransformPlugin,
+            SpatialPlugin2D,
+        ));
+        app
+    }
+
+    pub fn spawn(&self, world: &mut World) -> Vec<Entity> {
+        self.roots.iter().map(|root| root.spawn(world)).collect()
+    }
+
+    // Spawns into a fresh app, updates it the given number of times and snapshots the result
+    pub fn run(&self, frames: usize) -> SpatialSnapshot2D {
+        let mut app = Self::app();
+        self.spawn(app.world_mut());
+        for _ in 0..frames {
+            app.update();
+        }
+        SpatialSnapshot2D::from_world(app.world_mut())
+    }
+}
+
+impl SpatialSnapshot2D {
+    // Unnamed entities show up as #index so they still get a stable-ish path
+    pub fn from_world(world: &mut World) -> Self {
+        let mut entries: Vec<SnapshotEntry2D> = world
+            .query_filtered::<(Entity, &GlobalTransform), With<Position2D>>()
+            .iter(world)
+            .map(|(entity, transform)| {
+                let affine = transform.affine();
+                let world_from_local = Affine2::from_mat2_translation(
+                    Mat2::from_cols(
+                        affine.matrix3.x_axis.truncate(),
+                        affine.matrix3.y_axis.truncate(),
+                    ),
+                    affine.translation.truncate(),
+                );
+                SnapshotEntry2D::new(
+                    name_path(world, entity),
+                    world_from_local,
+                    affine.translation.z,
+                )
+            })
+            .collect();
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        Self { entries }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, SnapshotError2D> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(index, line)| {
+                SnapshotEntry2D::parse(line).ok_or_else(|| SnapshotError2D::Parse {
+                    line: index + 1,
+                    text: line.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SnapshotError2D> {
+        Self::parse(&std::fs::read_to_string(path).map_err(SnapshotError2D::Io)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError2D> {
+        std::fs::write(path, self.to_string()).map_err(SnapshotError2D::Io)
+    }
+
+    pub fn get(&self, path: &str) -> Option<&SnapshotEntry2D> {
+        self.entries.iter().find(|entry| entry.path == path)
+    }
+
+    // One line per difference, empty when everything is within tolerance.
+    // The tolerance is in world units for positions and scales and in degrees for rotations.
+    pub fn diff(&self, expected: &Self, tolerance: f32) -> Vec<String> {
+        let mut differences = Vec::new();
+        for wanted in &expected.entries {
+            let Some(actual) = self.get(&wanted.path) else {
+                differences.push(format!("{}: missing", wanted.path));
+                continue;
+            };
+            let close = |a: f32, b: f32| (a - b).abs() <= tolerance;
+            let mut mismatch = |field: &str, got: String, want: String| {
+                differences.push(format!(
+                    "{}: {field} is {got}, expected {want}",
+                    wanted.path
+                ))
+            };
+            if !close(actual.position.x, wanted.position.x)
+                || !close(actual.position.y, wanted.position.y)
+            {
+                mismatch("position", pair(actual.position), pair(wanted.position));
+            }
+            let turn = Radians::from_f32((actual.rotation - wanted.rotation).to_radians());
+            if turn.wrapped().to_degrees_f32().abs() > tolerance {
+                mismatch("rotation", num(actual.rotation), num(wanted.rotation));
+            }
+            if !close(actual.scale.x, wanted.scale.x) || !close(actual.scale.y, wanted.scale.y) {
+                mismatch("scale", pair(actual.scale), pair(wanted.scale));
+            }
+            if !close(actual.z, wanted.z) {
+                mismatch("z", num(actual.z), num(wanted.z));
+            }
+        }
+        for actual in &self.entries {
+            if expected.get(&actual.path).is_none() {
+                differences.push(format!("{}: unexpected", actual.path));
+            }
+        }
+        differences
+    }
+
+    pub fn assert_matches(&self, golden: &str, tolerance: f32) {
+        let expected = Self::parse(golden).unwrap_or_else(|error| panic!("{error}"));
+        self.assert_matches_snapshot(&expected, tolerance);
+    }
+
+    // Writes the file instead only when SPATIAL2D_BLESS is set, a missing file is a failure
+    pub fn assert_golden_file(&self, path: impl AsRef<Path>, tolerance: f32) {
+        let path = path.as_ref();
+        if blessing(path) {
+            self.save(path).unwrap_or_else(|error| panic!("{error}"));
+            return;
+        }
+        let expected =
+            Self::load(path).unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+        self.assert_matches_snapshot(&expected, tolerance);
+    }
+
+    fn assert_matches_snapshot(&self, expected: &Self, tolerance: f32) {
+        let differences = self.diff(expected, tolerance);
+        assert!(
+            differences.is_empty(),
+            "spatial snapshot differs:\n{}\n\nactual snapshot:\n{self}",
+            differences.join("\n")
+        );
+    }
+}
+
+impl SnapshotEntry2D {
+    pub fn new(path: impl Into<String>, world_from_local: Affine2, z: f32) -> Self {
+        let Affine2 {
+            matrix2,
+            translation,
+        } = world_from_local;
+        let scale_x = matrix2.x_axis.length();
+        Self {
+            path: path.into(),
+            position: translation,
+            rotation: Radians::from_f32(matrix2.x_axis.to_angle())
+                .wrapped()
+                .to_degrees_f32(),
+            scale: Vec2::new(
+                scale_x,
+                if scale_x > 0.0 {
+                    matrix2.determinant() / scale_x
+                } else {
+                    matrix2.y_axis.length()
+                },
+            ),
+            z,
+        }
+    }
+
+    // "path position=(x, y) rotation=deg scale=(x, y) z=z", names may contain spaces
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let split = [" position=", " rotation=", " scale=", " z="]
+            .iter()
+            .filter_map(|key| line.find(key))
+            .min()?;
+        let (path, fields) = line.split_at(split);
+        let fields = fields.replace(", ", ",");
+        let mut entry = Self {
+            path: path.to_string(),
+            position: Vec2::ZERO,
+            rotation: 0.0,
+            scale: Vec2::ONE,
+            z: 0.0,
+        };
+        for field in fields.split_whitespace() {
+            let (key, value) = field.split_once('=')?;
+            match key {
+                "position" => entry.position = parse_pair(value)?,
+                "rotation" => entry.rotation = value.parse().ok()?,
+                "scale" => entry.scale = parse_pair(value)?,
+                "z" => entry.z = value.parse().ok()?,
+                _ => return None,
+            }
+        }
+        Some(entry)
+    }
+}
+
+// Golden files for text output such as SvgExport2D::render, compared line by line
+pub fn assert_golden_text(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    if blessing(path) {
+        std::fs::write(path, actual).unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+        return;
+    }
+    let expected =
+        std::fs::read_to_string(path).unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+    let (mut expected_lines, mut actual_lines) = (expected.lines(), actual.lines());
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => break,
+            (want, got) if want == got => {}
+            (want, got) => panic!(
+                "{} differs at line {line}:\nexpected: {}\n  actual: {}\n\nset {SNAPSHOT_BLESS_VAR} to accept the new output",
+                path.display(),
+                want.unwrap_or("<end of file>"),
+                got.unwrap_or("<end of output>"),
+            ),
+        }
+    }
+}
+
+// So a golden file that was never checked in can't pass CI by writing itself
+fn blessing(path: &Path) -> bool {
+    if std::env::var_os(SNAPSHOT_BLESS_VAR).is_some() {
+        return true;
+    }
+    assert!(
+        path.exists(),
+        "{} is missing, run with {SNAPSHOT_BLESS_VAR}=1 to write it",
+        path.display()
+    );
+    false
+}
+
+fn parse_pair(value: &str) -> Option<Vec2> {
+    let (x, y) = value
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .split_once(',')?;
+    Some(Vec2::new(x.trim().parse().ok()?, y.trim().parse().ok()?))
+}
+
+fn pair(value: Vec2) -> String {
+    format!("({}, {})", num(value.x), num(value.y))
+}
+
+// Fixed precision and no negative zero, so float noise doesn't churn golden files
+fn num(value: f32) -> String {
+    let value = if value.abs() < 0.00005 { 0.0 } else { value };
+    format!("{value:.4}")
+}
+
+impl fmt::Display for SpatialSnapshot2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SnapshotEntry2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} position={} rotation={} scale={} z={}",
+            self.path,
+            pair(self.position),
+            num(self.rotation),
+            pair(self.scale),
+            num(self.z)
+        )
+    }
+}
+
+impl fmt::Display for SnapshotError2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::Parse { line, text } => write!(f, "bad snapshot line {line}: {text}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError2D {}
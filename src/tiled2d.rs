@@ -0,0 +1,485 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::{fmt, path::Path};
+
+const FLIP_X: u32 = 0x8000_0000;
+const FLIP_Y: u32 = 0x4000_0000;
+const FLIP_DIAGONAL: u32 = 0x2000_0000;
+// Also drops the hexagonal 120 degree rotation bit
+const GID_MASK: u32 = 0x0FFF_FFFF;
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct TiledMap2D {
+    // In tiles, zero for infinite maps
+    pub size: UVec2,
+    // In world units
+    pub tile_size: Vec2,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect, Deserialize)]
+#[reflect(Default)]
+pub enum TiledLayerKind2D {
+    #[default]
+    #[serde(rename = "tilelayer")]
+    Tiles,
+    #[serde(rename = "objectgroup")]
+    Objects,
+    #[serde(rename = "imagelayer")]
+    Image,
+    #[serde(rename = "group")]
+    Group,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct TiledLayer2D {
+    pub id: u32,
+    pub kind: TiledLayerKind2D,
+    pub opacity: f32,
+}
+
+// Flips are already applied to Scale2D and Rotation2D, they're kept for picking sprites
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct TiledTile2D {
+    pub gid: u32,
+    pub flip_x: bool,
+    pub flip_y: bool,
+    pub flip_diagonal: bool,
+}
+
+// Points are y-up and relative to the object's Position2D
+#[derive(Default, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Default)]
+pub enum TiledShape2D {
+    #[default]
+    Rect,
+    Ellipse,
+    Point,
+    Polygon(Vec<Vec2>),
+    Polyline(Vec<Vec2>),
+}
+
+#[derive(Default, Clone, PartialEq, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct TiledObject2D {
+    pub id: u32,
+    pub class: String,
+    pub gid: Option<u32>,
+    pub size: Vec2,
+    pub shape: TiledShape2D,
+}
+
+// A Tiled JSON map, ready to spawn. Only orthogonal maps with CSV tile data are supported,
+// Tiled's y-down pixels become y-up world units with the bottom left of the map at the origin.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TiledDocument2D {
+    pub name: String,
+    pub pixels_per_unit: f32,
+    map: Map,
+}
+
+#[derive(Debug)]
+pub enum TiledImportError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Unsupported(String),
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+struct Map {
+    #[serde(default = "orthogonal")]
+    orientation: String,
+    #[serde(default)]
+    infinite: bool,
+    width: u32,
+    height: u32,
+    tilewidth: f32,
+    tileheight: f32,
+    #[serde(default)]
+    layers: Vec<Layer>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+struct Layer {
+    #[serde(default)]
+    id: u32,
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    kind: TiledLayerKind2D,
+    #[serde(default)]
+    offsetx: f32,
+    #[serde(default)]
+    offsety: f32,
+    #[serde(default = "visible")]
+    visible: bool,
+    #[serde(default = "opaque")]
+    opacity: f32,
+    #[serde(default)]
+    width: u32,
+    data: Option<TileData>,
+    #[serde(default)]
+    chunks: Vec<Chunk>,
+    #[serde(default)]
+    draworder: ObjectOrder,
+    #[serde(default)]
+    objects: Vec<Object>,
+    #[serde(default)]
+    layers: Vec<Layer>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(untagged)]
+enum TileData {
+    Gids(Vec<u32>),
+    Encoded(String),
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+struct Chunk {
+    data: TileData,
+    x: i32,
+    y: i32,
+    width: u32,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ObjectOrder {
+    #[default]
+    TopDown,
+    Index,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+struct Object {
+    #[serde(default)]
+    id: u32,
+    #[serde(default)]
+    name: String,
+    // Tiled 1.9 briefly wrote this as "class"
+    #[serde(default, rename = "type", alias = "class")]
+    class: String,
+    x: f32,
+    y: f32,
+    #[serde(default)]
+    width: f32,
+    #[serde(default)]
+    height: f32,
+    // Degrees clockwise
+    #[serde(default)]
+    rotation: f32,
+    gid: Option<u32>,
+    #[serde(default = "visible")]
+    visible: bool,
+    #[serde(default)]
+    point: bool,
+    #[serde(default)]
+    ellipse: bool,
+    polygon: Option<Vec<Point>>,
+    polyline: Option<Vec<Point>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+fn orthogonal() -> String {
+    "orthogonal".to_string()
+}
+
+fn visible() -> bool {
+    true
+}
+
+fn opaque() -> f32 {
+    1.0
+}
+
+impl TiledDocument2D {
+    pub fn from_json(text: &str) -> Result<Self, TiledImportError> {
+        let map: Map = serde_json::from_str(text).map_err(TiledImportError::Json)?;
+        if map.orientation != "orthogonal" {
+            return Err(TiledImportError::Unsupported(format!(
+                "{} orientation",
+                map.orientation
+            )));
+        }
+        check_layers(&map.layers)?;
+        Ok(Self {
+            name: "map".to_string(),
+            pixels_per_unit: 1.0,
+            map,
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TiledImportError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(TiledImportError::Io)?;
+        let mut document = Self::from_json(&text)?;
+        if let Some(stem) = path.file_stem() {
+            document.name = stem.to_string_lossy().into_owned();
+        }
+        Ok(document)
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_pixels_per_unit(mut self, pixels_per_unit: f32) -> Self {
+        assert!(pixels_per_unit > 0.0);
+        self.pixels_per_unit = pixels_per_unit;
+        self
+    }
+
+    pub fn tile_size(&self) -> Vec2 {
+        Vec2::new(self.map.tilewidth, self.map.tileheight) / self.pixels_per_unit
+    }
+
+    // Layers are one DrawOrder apart at the top level, everything inside a layer or group
+    // is spread within that one step so it can never overlap the next layer
+    pub fn spawn(&self, world: &mut World) -> Entity {
+        let size = if self.map.infinite {
+            UVec2::ZERO
+        } else {
+            UVec2::new(self.map.width, self.map.height)
+        };
+        let root = world
+            .spawn((
+                SpatialBundle2D::default(),
+                Name::new(self.name.clone()),
+                TiledMap2D {
+                    size,
+                    tile_size: self.tile_size(),
+                },
+            ))
+            .id();
+        self.spawn_layers(world, root, &self.map.layers, self.map.layers.len() as f32);
+        root
+    }
+
+    fn spawn_layers(&self, world: &mut World, parent: Entity, layers: &[Layer], span: f32) {
+        let step = span / layers.len().max(1) as f32;
+        for (index, layer) in layers.iter().enumerate() {
+            let entity = world
+                .spawn((
+                    SpatialBundle2D {
+                        position: Position2D::new(layer.offsetx, -layer.offsety)
+                            / self.pixels_per_unit,
+                        draw_order: DrawOrder::new(index as f32 * step),
+                        spatial: visibility(layer.visible),
+                        ..default()
+                    },
+                    Name::new(layer.name.clone()),
+                    TiledLayer2D {
+                        id: layer.id,
+                        kind: layer.kind,
+                        opacity: layer.opacity,
+                    },
+                ))
+                .set_parent(parent)
+                .id();
+            match layer.kind {
+                TiledLayerKind2D::Tiles => self.spawn_tiles(world, entity, layer),
+                TiledLayerKind2D::Objects => self.spawn_objects(world, entity, layer, step),
+                TiledLayerKind2D::Group => self.spawn_layers(world, entity, &layer.layers, step),
+                TiledLayerKind2D::Image => {}
+            }
+        }
+    }
+
+    fn spawn_tiles(&self, world: &mut World, layer_entity: Entity, layer: &Layer) {
+        let mut spawn = |data: &[u32], origin: IVec2, width: u32| {
+            for (index, raw) in data.iter().enumerate() {
+                if raw & GID_MASK == 0 {
+                    continue;
+                }
+                let cell =
+                    origin + IVec2::new(index as i32 % width as i32, index as i32 / width as i32);
+                let center = Vec2::new(
+                    (cell.x as f32 + 0.5) * self.map.tilewidth,
+                    self.flip_y((cell.y as f32 + 0.5) * self.map.tileheight),
+                );
+                let (rotation, scale) = flips(*raw);
+                world
+                    .spawn((
+                        SpatialBundle2D {
+                            position: (center / self.pixels_per_unit).into(),
+                            rotation,
+                            scale,
+                            ..default()
+                        },
+                        GridCoord::new(cell.x, self.rows() - 1 - cell.y),
+                        TiledTile2D {
+                            gid: raw & GID_MASK,
+                            flip_x: raw & FLIP_X != 0,
+                            flip_y: raw & FLIP_Y != 0,
+                            flip_diagonal: raw & FLIP_DIAGONAL != 0,
+                        },
+                    ))
+                    .set_parent(layer_entity);
+            }
+        };
+        if let Some(TileData::Gids(data)) = &layer.data {
+            spawn(data, IVec2::ZERO, layer.width.max(1));
+        }
+        for chunk in &layer.chunks {
+            if let TileData::Gids(data) = &chunk.data {
+                spawn(data, IVec2::new(chunk.x, chunk.y), chunk.width.max(1));
+            }
+        }
+    }
+
+    fn spawn_objects(&self, world: &mut World, layer_entity: Entity, layer: &Layer, span: f32) {
+        let mut objects: Vec<&Object> = layer.objects.iter().collect();
+        if layer.draworder == ObjectOrder::TopDown {
+            objects.sort_by(|a, b| a.y.total_cmp(&b.y));
+        }
+        let step = span / objects.len().max(1) as f32;
+        for (index, object) in objects.into_iter().enumerate() {
+            let shape = shape(object, self.pixels_per_unit);
+            let size = Vec2::new(object.width, object.height) / self.pixels_per_unit;
+            let (flip_rotation, scale) = object.gid.map(flips).unwrap_or_default();
+            let rotation = Rotation2D::from_f32_degrees(-object.rotation);
+
+            // Tiled anchors rects at the top left and tile objects at the bottom left, both
+            // rotating around that corner. Everything here is centred instead so flips and
+            // bounds work the same way they do for sprites.
+            let to_center = match (&shape, object.gid) {
+                (_, Some(_)) => size * 0.5,
+                (TiledShape2D::Rect | TiledShape2D::Ellipse, None) => {
+                    Vec2::new(size.x, -size.y) * 0.5
+                }
+                _ => Vec2::ZERO,
+            };
+            let anchor = Vec2::new(object.x, self.flip_y(object.y)) / self.pixels_per_unit;
+            let center = anchor + Vec2::from(rotation.radians()).rotate(to_center);
+
+            let mut entity = world.spawn((
+                SpatialBundle2D {
+                    position: center.into(),
+                    rotation: rotation + flip_rotation.radians(),
+                    scale,
+                    draw_order: DrawOrder::new(index as f32 * step),
+                    spatial: visibility(object.visible),
+                    ..default()
+                },
+                TiledObject2D {
+                    id: object.id,
+                    class: object.class.clone(),
+                    gid: object.gid.map(|gid| gid & GID_MASK),
+                    size,
+                    shape: shape.clone(),
+                },
+            ));
+            if !object.name.is_empty() {
+                entity.insert(Name::new(object.name.clone()));
+            }
+            if size.x > 0.0 && size.y > 0.0 {
+                match shape {
+                    TiledShape2D::Ellipse if size.x == size.y => {
+                        entity.insert(Bounds2D::circle(size.x * 0.5));
+                    }
+                    TiledShape2D::Rect | TiledShape2D::Ellipse => {
+                        entity.insert(Bounds2D::rect(size));
+                    }
+                    _ => {}
+                }
+            }
+            entity.set_parent(layer_entity);
+        }
+    }
+
+    // Infinite maps have no bottom edge, their origin stays at Tiled's top left
+    fn flip_y(&self, y: f32) -> f32 {
+        self.rows() as f32 * self.map.tileheight - y
+    }
+
+    fn rows(&self) -> i32 {
+        if self.map.infinite {
+            0
+        } else {
+            self.map.height as i32
+        }
+    }
+}
+
+fn check_layers(layers: &[Layer]) -> Result<(), TiledImportError> {
+    for layer in layers {
+        let encoded = matches!(layer.data, Some(TileData::Encoded(_)))
+            || layer
+                .chunks
+                .iter()
+                .any(|chunk| matches!(chunk.data, TileData::Encoded(_)));
+        if encoded {
+            return Err(TiledImportError::Unsupported(format!(
+                "encoded tile data in layer {}, export it with the CSV layer format",
+                layer.name
+            )));
+        }
+        check_layers(&layer.layers)?;
+    }
+    Ok(())
+}
+
+// Tiled applies the diagonal flip first, then the horizontal and vertical ones
+fn flips(raw: u32) -> (Rotation2D, Scale2D) {
+    let x = if raw & FLIP_X != 0 { -1.0 } else { 1.0 };
+    let y = if raw & FLIP_Y != 0 { -1.0 } else { 1.0 };
+    if raw & FLIP_DIAGONAL != 0 {
+        // Swapping axes in y-down space is a quarter turn plus a mirror in y-up space
+        (Rotation2D::from_f32_degrees(90.0), Scale2D::new(-y, x))
+    } else {
+        (Rotation2D::default(), Scale2D::new(x, y))
+    }
+}
+
+fn shape(object: &Object, pixels_per_unit: f32) -> TiledShape2D {
+    let points = |points: &[Point]| {
+        points
+            .iter()
+            .map(|point| Vec2::new(point.x, -point.y) / pixels_per_unit)
+            .collect()
+    };
+    if let Some(polygon) = &object.polygon {
+        TiledShape2D::Polygon(points(polygon))
+    } else if let Some(polyline) = &object.polyline {
+        TiledShape2D::Polyline(points(polyline))
+    } else if object.point {
+        TiledShape2D::Point
+    } else if object.ellipse {
+        TiledShape2D::Ellipse
+    } else {
+        TiledShape2D::Rect
+    }
+}
+
+fn visibility(visible: bool) -> SpatialBundle {
+    SpatialBundle {
+        visibility: if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        },
+        ..default()
+    }
+}
+
+impl fmt::Display for TiledImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::Json(error) => write!(f, "{error}"),
+            Self::Unsupported(what) => write!(f, "unsupported Tiled map: {what}"),
+        }
+    }
+}
+
+impl std::error::Error for TiledImportError {}
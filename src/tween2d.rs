@@ -0,0 +1,397 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+use std::time::Duration;
+
+// Writes an interpolated value into a component, `t` is already eased
+pub trait Lens2D: Send + Sync + 'static {
+    type Component: Component;
+
+    fn sample(&mut self, target: &mut Self::Component, t: f32);
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum TweenRepeat2D {
+    #[default]
+    Once,
+    Times(u32),
+    Forever,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum RotationTween2D {
+    #[default]
+    Shortest,
+    // Goes through every angle between start and end, so 0 to 720 degrees spins twice
+    Direct,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum TweenEventKind2D {
+    StepCompleted(usize),
+    LoopCompleted,
+    Completed,
+}
+
+#[derive(Event, Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+pub struct TweenEvent2D {
+    pub entity: Entity,
+    pub kind: TweenEventKind2D,
+}
+
+// A missing start is taken from the component when the tween first runs
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct PositionLens {
+    pub start: Option<Position2D>,
+    pub end: Position2D,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct RotationLens {
+    pub start: Option<Radians>,
+    pub end: Radians,
+    pub mode: RotationTween2D,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct ScaleLens {
+    pub start: Option<Scale2D>,
+    pub end: Scale2D,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct DrawOrderLens {
+    pub start: Option<DrawOrder>,
+    pub end: DrawOrder,
+}
+
+struct TweenStep<L> {
+    lens: L,
+    duration: Duration,
+    easing: Easing,
+}
+
+// Plays its steps one after another, repeating the whole sequence
+#[derive(Component)]
+pub struct Tween2D<L: Lens2D> {
+    steps: Vec<TweenStep<L>>,
+    pub delay: Duration,
+    pub repeat: TweenRepeat2D,
+    pub yoyo: bool,
+    pub paused: bool,
+    elapsed: Duration,
+    current_step: usize,
+    completed_loops: u32,
+    finished: bool,
+}
+
+impl PositionLens {
+    pub fn new(start: Position2D, end: Position2D) -> Self {
+        Self {
+            start: Some(start),
+            end,
+        }
+    }
+
+    pub fn to(end: Position2D) -> Self {
+        Self { start: None, end }
+    }
+}
+
+impl RotationLens {
+    pub fn new(start: impl Into<Rotation2D>, end: impl Into<Rotation2D>) -> Self {
+        Self {
+            start: Some(start.into().radians()),
+            end: end.into().radians(),
+            mode: RotationTween2D::Shortest,
+        }
+    }
+
+    pub fn to(end: impl Into<Rotation2D>) -> Self {
+        Self {
+            start: None,
+            end: end.into().radians(),
+            mode: RotationTween2D::Shortest,
+        }
+    }
+
+    // Unwrapped angles, for full turns
+    pub fn direct(start: Option<Radians>, end: Radians) -> Self {
+        Self {
+            start,
+            end,
+            mode: RotationTween2D::Direct,
+        }
+    }
+}
+
+impl ScaleLens {
+    pub fn new(start: Scale2D, end: Scale2D) -> Self {
+        Self {
+            start: Some(start),
+            end,
+        }
+    }
+
+    pub fn to(end: Scale2D) -> Self {
+        Self { start: None, end }
+    }
+}
+
+impl DrawOrderLens {
+    pub fn new(start: DrawOrder, end: DrawOrder) -> Self {
+        Self {
+            start: Some(start),
+            end,
+        }
+    }
+
+    pub fn to(end: DrawOrder) -> Self {
+        Self { start: None, end }
+    }
+}
+
+impl Lens2D for PositionLens {
+    type Component = Position2D;
+
+    fn sample(&mut self, target: &mut Position2D, t: f32) {
+        let start = *self.start.get_or_insert(*target);
+        *target = Vec2::from(start).lerp(self.end.into(), t).into();
+    }
+}
+
+impl Lens2D for RotationLens {
+    type Component = Rotation2D;
+
+    fn sample(&mut self, target: &mut Rotation2D, t: f32) {
+        let start = *self.start.get_or_insert(target.radians());
+        let arc = match self.mode {
+            RotationTween2D::Shortest => start.shortest_to(self.end),
+            RotationTween2D::Direct => self.end - start,
+        };
+        *target = (start + arc * t).into();
+    }
+}
+
+impl Lens2D for ScaleLens {
+    type Component = Scale2D;
+
+    fn sample(&mut self, target: &mut Scale2D, t: f32) {
+        let start = *self.start.get_or_insert(*target);
+        let scale = Vec2::from(start).lerp(self.end.into(), t);
+        // Flipping passes through zero, which Scale2D can't hold, so hold the last value there
+        if scale.x != 0.0 {
+            target.x = scale.x;
+        }
+        if scale.y != 0.0 {
+            target.y = scale.y;
+        }
+    }
+}
+
+impl Lens2D for DrawOrderLens {
+    type Component = DrawOrder;
+
+    fn sample(&mut self, target: &mut DrawOrder, t: f32) {
+        let start = f32::from(*self.start.get_or_insert(*target));
+        let end = f32::from(self.end);
+        *target = DrawOrder::new(start + (end - start) * t);
+    }
+}
+
+impl<L: Lens2D> Tween2D<L> {
+    pub fn new(lens: L, duration: Duration, easing: Easing) -> Self {
+        Self {
+            steps: vec![TweenStep {
+                lens,
+                duration,
+                easing,
+            }],
+            delay: Duration::ZERO,
+            repeat: TweenRepeat2D::Once,
+            yoyo: false,
+            paused: false,
+            elapsed: Duration::ZERO,
+            current_step: 0,
+            completed_loops: 0,
+            finished: false,
+        }
+    }
+
+    pub fn then(mut self, lens: L, duration: Duration, easing: Easing) -> Self {
+        self.steps.push(TweenStep {
+            lens,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_repeat(mut self, repeat: TweenRepeat2D) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    // Every other loop plays backwards
+    pub fn with_yoyo(mut self, yoyo: bool) -> Self {
+        self.yoyo = yoyo;
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.steps.iter().map(|step| step.duration).sum()
+    }
+
+    pub fn restart(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.current_step = 0;
+        self.completed_loops = 0;
+        self.finished = false;
+    }
+
+    // Advances time and writes the new value, reporting what happened along the way
+    pub fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut L::Component,
+        mut on_event: impl FnMut(TweenEventKind2D),
+    ) {
+        if self.finished || self.paused || self.steps.is_empty() {
+            return;
+        }
+        self.elapsed += delta;
+        let Some(playing) = self.elapsed.checked_sub(self.delay) else {
+            return;
+        };
+
+        let length = self.duration().as_secs_f32();
+        let playing = playing.as_secs_f32();
+        let loops = match self.repeat {
+            TweenRepeat2D::Once => 1,
+            TweenRepeat2D::Times(times) => times.max(1),
+            TweenRepeat2D::Forever => u32::MAX,
+        };
+        let (mut loop_index, mut local) = if length > 0.0 {
+            ((playing / length) as u32, playing % length)
+        } else {
+            (loops, 0.0)
+        };
+        if loop_index >= loops {
+            loop_index = loops - 1;
+            local = length;
+            self.finished = true;
+        }
+        let last = self.steps.len() - 1;
+        // Steps left behind still get their end value, or the next step would start from
+        // wherever the last frame happened to leave the component
+        while self.completed_loops < loop_index {
+            let backwards = self.plays_backwards(self.completed_loops);
+            let end = if backwards { 0 } else { last };
+            self.finish_steps(self.current_step, end, backwards, target, &mut on_event);
+            self.completed_loops += 1;
+            on_event(TweenEventKind2D::LoopCompleted);
+            // Very long ticks on short repeating tweens could otherwise spin through every
+            // loop, so once the one in progress is done only the last 64 are played out.
+            // The skipped ones send no events, the later loops write every step anyway.
+            self.completed_loops = self.completed_loops.max(loop_index.saturating_sub(64));
+            self.current_step = if self.plays_backwards(self.completed_loops) {
+                last
+            } else {
+                0
+            };
+        }
+        let backwards = self.plays_backwards(loop_index);
+        if backwards {
+            local = length - local;
+        }
+
+        let mut start = 0.0;
+        let mut step_index = last;
+        for (index, step) in self.steps.iter().enumerate() {
+            let end = start + step.duration.as_secs_f32();
+            if local < end || index == last {
+                step_index = index;
+                break;
+            }
+            start = end;
+        }
+        if backwards && step_index < self.current_step {
+            let before = step_index + 1;
+            self.finish_steps(self.current_step, before, true, target, &mut on_event);
+        } else if !backwards && step_index > self.current_step {
+            let before = step_index - 1;
+            self.finish_steps(self.current_step, before, false, target, &mut on_event);
+        }
+        self.current_step = step_index;
+
+        let step = &mut self.steps[step_index];
+        let duration = step.duration.as_secs_f32();
+        let t = if duration > 0.0 {
+            ((local - start) / duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let eased = step.easing.sample(t);
+        step.lens.sample(target, eased);
+
+        if self.finished {
+            on_event(TweenEventKind2D::StepCompleted(step_index));
+            on_event(TweenEventKind2D::LoopCompleted);
+            on_event(TweenEventKind2D::Completed);
+        }
+    }
+
+    fn plays_backwards(&self, loop_index: u32) -> bool {
+        self.yoyo && loop_index % 2 == 1
+    }
+
+    // Writes the end value of every step from `from` to `to`, in the order they play
+    fn finish_steps(
+        &mut self,
+        from: usize,
+        to: usize,
+        backwards: bool,
+        target: &mut L::Component,
+        on_event: &mut impl FnMut(TweenEventKind2D),
+    ) {
+        for offset in 0..=from.abs_diff(to) {
+            let index = if backwards {
+                from - offset
+            } else {
+                from + offset
+            };
+            let step = &mut self.steps[index];
+            let t = if backwards { 0.0 } else { 1.0 };
+            step.lens.sample(target, step.easing.sample(t));
+            on_event(TweenEventKind2D::StepCompleted(index));
+        }
+    }
+}
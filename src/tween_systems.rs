@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub fn tween2d<L: Lens2D>(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Tween2D<L>, &mut L::Component)>,
+    mut events: EventWriter<TweenEvent2D>,
+) {
+    for (entity, mut tween, mut target) in query.iter_mut() {
+        if tween.is_finished() || tween.paused {
+            continue;
+        }
+        tween.tick(time.delta(), &mut target, |kind| {
+            events.send(TweenEvent2D { entity, kind });
+        });
+    }
+}
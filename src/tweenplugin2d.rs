@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+// Custom lenses need their own tween2d::<L> system added in SpatialSystems2D::Tween
+pub struct TweenPlugin2D;
+
+impl Plugin for TweenPlugin2D {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Easing>()
+            .register_type::<TweenRepeat2D>()
+            .register_type::<RotationTween2D>()
+            .register_type::<TweenEventKind2D>()
+            .register_type::<PositionLens>()
+            .register_type::<RotationLens>()
+            .register_type::<ScaleLens>()
+            .register_type::<DrawOrderLens>()
+            .add_event::<TweenEvent2D>()
+            .add_systems(
+                Update,
+                (
+                    tween2d::<PositionLens>,
+                    tween2d::<RotationLens>,
+                    tween2d::<ScaleLens>,
+                    tween2d::<DrawOrderLens>,
+                )
+                    .in_set(SpatialSystems2D::Tween),
+            );
+    }
+}
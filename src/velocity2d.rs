@@ -0,0 +1,213 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Velocity2D {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub enum VelocityFrame2D {
+    // Same space as Position2D
+    #[default]
+    Parent,
+    // Relative to the entity's own facing, x is forward
+    Local,
+    World,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect, Deref, DerefMut)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct AngularVelocity2D(pub Radians);
+
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct ScaleVelocity2D {
+    pub x: f32,
+    pub y: f32,
+}
+
+// Exponential decay rates per second, 0 disables damping
+#[derive(Default, Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct Damping2D {
+    pub linear: f32,
+    pub angular: f32,
+    pub scale: f32,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
+pub struct MaxSpeed2D {
+    pub linear: f32,
+    pub angular: Radians,
+}
+
+impl Velocity2D {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self::from_f32(x, y)
+    }
+
+    pub fn from_f32(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn from_direction(direction: impl Into<Rotation2D>, speed: f32) -> Self {
+        (Vec2::from(direction.into().radians()) * speed).into()
+    }
+
+    pub fn speed(&self) -> f32 {
+        Vec2::from(self).length()
+    }
+
+    pub const ZERO: Self = Self { x: 0., y: 0. };
+}
+
+impl AngularVelocity2D {
+    pub fn from_radians(radians: f32) -> Self {
+        Self(Radians::from_f32(radians))
+    }
+
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self(Radians::from_f32(degrees.to_radians()))
+    }
+
+    pub const ZERO: Self = Self(Radians::ZERO);
+}
+
+impl ScaleVelocity2D {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self::from_f32(x, y)
+    }
+
+    pub fn from_f32(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn splat(value: f32) -> Self {
+        Self::from_f32(value, value)
+    }
+
+    pub const ZERO: Self = Self { x: 0., y: 0. };
+}
+
+impl Damping2D {
+    pub fn new(linear: f32, angular: f32, scale: f32) -> Self {
+        Self {
+            linear,
+            angular,
+            scale,
+        }
+    }
+
+    pub fn linear(linear: f32) -> Self {
+        Self::new(linear, 0.0, 0.0)
+    }
+
+    pub fn angular(angular: f32) -> Self {
+        Self::new(0.0, angular, 0.0)
+    }
+}
+
+impl MaxSpeed2D {
+    pub fn new(linear: f32, angular: Radians) -> Self {
+        Self { linear, angular }
+    }
+
+    pub fn linear(linear: f32) -> Self {
+        Self {
+            linear,
+            ..default()
+        }
+    }
+
+    pub fn angular(angular: Radians) -> Self {
+        Self {
+            angular,
+            ..default()
+        }
+    }
+}
+
+mod default {
+    use super::*;
+    impl Default for MaxSpeed2D {
+        fn default() -> Self {
+            Self {
+                linear: f32::INFINITY,
+                angular: Radians::from_f32(f32::INFINITY),
+            }
+        }
+    }
+}
+
+mod from {
+    use super::*;
+
+    impl From<Vec2> for Velocity2D {
+        fn from(value: Vec2) -> Self {
+            Self::from_f32(value.x, value.y)
+        }
+    }
+
+    impl From<Degrees> for AngularVelocity2D {
+        fn from(degrees: Degrees) -> Self {
+            Self::from_degrees(degrees.to_f32())
+        }
+    }
+
+    impl From<Radians> for AngularVelocity2D {
+        fn from(radians: Radians) -> Self {
+            Self(radians)
+        }
+    }
+
+    impl From<Vec2> for ScaleVelocity2D {
+        fn from(value: Vec2) -> Self {
+            Self::from_f32(value.x, value.y)
+        }
+    }
+}
+
+mod into {
+    use super::*;
+
+    impl From<Velocity2D> for Vec2 {
+        fn from(velocity: Velocity2D) -> Self {
+            Vec2::new(velocity.x, velocity.y)
+        }
+    }
+
+    impl From<&Velocity2D> for Vec2 {
+        fn from(velocity: &Velocity2D) -> Self {
+            Vec2::new(velocity.x, velocity.y)
+        }
+    }
+
+    impl From<ScaleVelocity2D> for Vec2 {
+        fn from(velocity: ScaleVelocity2D) -> Self {
+            Vec2::new(velocity.x, velocity.y)
+        }
+    }
+
+    impl From<&ScaleVelocity2D> for Vec2 {
+        fn from(velocity: &ScaleVelocity2D) -> Self {
+            Vec2::new(velocity.x, velocity.y)
+        }
+    }
+}
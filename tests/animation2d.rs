@@ -0,0 +1,131 @@
+mod common;
+
+use bevy::{animation::Keyframes, prelude::*};
+use common::app;
+use rantz_spatial2d::prelude::*;
+use std::f32::consts::PI;
+
+const EPSILON: f32 = 1e-3;
+
+#[test]
+fn curves_pack_every_channel_into_transform() {
+    let curves = SpatialCurves2D::default()
+        .with_position([
+            (0.0, Position2D::new(1.0, 2.0)),
+            (1.0, Position2D::new(3.0, 4.0)),
+        ])
+        .with_draw_order([(0.5, DrawOrder::new(6.0))])
+        .with_scale([
+            (0.0, Scale2D::new(1.0, 1.0)),
+            (0.5, Scale2D::new(2.0, -4.0)),
+        ])
+        // Three half turns, which a quaternion couldn't tell apart from one
+        .with_rotation([
+            (0.0, Radians::from_f32(0.0)),
+            (1.0, Radians::from_f32(3.0 * PI)),
+        ]);
+    let animated = curves.animated();
+    assert!(animated.position && animated.rotation && animated.scale && animated.draw_order);
+
+    let mut translation = None;
+    let mut scale = None;
+    for curve in curves.curves() {
+        assert_eq!(curve.keyframe_timestamps, vec![0.0, 0.5, 1.0]);
+        match curve.keyframes {
+            Keyframes::Translation(keys) => translation = Some(keys),
+            Keyframes::Scale(keys) => scale = Some(keys),
+            _ => panic!("only translation and scale carry 2D channels"),
+        }
+    }
+    let translation = translation.unwrap();
+    assert!(translation[0].abs_diff_eq(Vec3::new(1.0, 2.0, 6.0), EPSILON));
+    assert!(translation[1].abs_diff_eq(Vec3::new(2.0, 3.0, 6.0), EPSILON));
+    assert!(translation[2].abs_diff_eq(Vec3::new(3.0, 4.0, 6.0), EPSILON));
+    // Scale in x and y, the unwrapped angle in z
+    let scale = scale.unwrap();
+    assert!(scale[0].abs_diff_eq(Vec3::new(1.0, 1.0, 0.0), EPSILON));
+    assert!(scale[1].abs_diff_eq(Vec3::new(2.0, -4.0, 1.5 * PI), EPSILON));
+    assert!(scale[2].abs_diff_eq(Vec3::new(2.0, -4.0, 3.0 * PI), EPSILON));
+}
+
+#[test]
+fn animated_transforms_round_trip_through_the_components() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((SpatialBundle2D::default(), Animated2D::all()))
+        .id();
+    // Nothing is known about the Transform until propagation has run once
+    app.update();
+
+    // What an AnimationPlayer would write from SpatialCurves2D
+    let mut transform = app.world_mut().get_mut::<Transform>(entity).unwrap();
+    transform.translation = Vec3::new(5.0, -6.0, 7.0);
+    transform.scale = Vec3::new(2.0, -3.0, 0.75 * PI);
+    app.update();
+
+    let world = app.world();
+    assert_eq!(
+        *world.get::<Position2D>(entity).unwrap(),
+        Position2D::new(5.0, -6.0)
+    );
+    assert_eq!(f32::from(*world.get::<DrawOrder>(entity).unwrap()), 7.0);
+    let scale = world.get::<Scale2D>(entity).unwrap();
+    assert_eq!((scale.x, scale.y), (2.0, -3.0));
+    let rotation = world.get::<Rotation2D>(entity).unwrap();
+    assert!((rotation.radians().to_f32() - 0.75 * PI).abs() < EPSILON);
+
+    // Propagation turns the components back into an ordinary Transform
+    let transform = world.get::<Transform>(entity).unwrap();
+    assert!(transform
+        .scale
+        .abs_diff_eq(Vec3::new(2.0, -3.0, 1.0), EPSILON));
+    assert!(transform
+        .rotation
+        .abs_diff_eq(Quat::from_rotation_z(0.75 * PI), EPSILON));
+    assert!(transform
+        .translation
+        .abs_diff_eq(Vec3::new(5.0, -6.0, 7.0), EPSILON));
+}
+
+#[test]
+fn only_animated_channels_are_synced() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                rotation: Rotation2D::from_f32_degrees(30.0),
+                ..default()
+            },
+            SpatialCurves2D::default()
+                .with_position([(0.0, Position2D::default())])
+                .animated(),
+        ))
+        .id();
+    app.update();
+    // Fresh spawns keep their components even though their Transform is still the default
+    let degrees = |app: &App| {
+        app.world()
+            .get::<Rotation2D>(entity)
+            .unwrap()
+            .degrees()
+            .to_f32()
+    };
+    assert!((degrees(&app) - 30.0).abs() < EPSILON);
+
+    let mut transform = app.world_mut().get_mut::<Transform>(entity).unwrap();
+    transform.translation = Vec3::new(1.0, 1.0, 9.0);
+    transform.scale = Vec3::new(4.0, 4.0, 0.0);
+    app.update();
+
+    let world = app.world();
+    assert_eq!(
+        *world.get::<Position2D>(entity).unwrap(),
+        Position2D::new(1.0, 1.0)
+    );
+    assert_eq!(f32::from(*world.get::<DrawOrder>(entity).unwrap()), 0.0);
+    let scale = world.get::<Scale2D>(entity).unwrap();
+    assert_eq!((scale.x, scale.y), (1.0, 1.0));
+    assert!((degrees(&app) - 30.0).abs() < EPSILON);
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use bevy::{math::Affine2, prelude::*};
+use common::app;
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+fn rect_eq(a: Rect, b: Rect) -> bool {
+    a.min.abs_diff_eq(b.min, EPSILON) && a.max.abs_diff_eq(b.max, EPSILON)
+}
+
+#[test]
+fn rotated_rects_grow_their_aabb_and_keep_their_obb() {
+    let affine = Affine2::from_scale_angle_translation(
+        Vec2::new(2.0, 1.0),
+        45f32.to_radians(),
+        Vec2::new(10.0, 0.0),
+    );
+    let bounds = WorldBounds2D::from_affine(&Bounds2D::rect(Vec2::new(2.0, 2.0)), affine);
+
+    // Half axes are (sqrt 2, sqrt 2) and (-sqrt 0.5, sqrt 0.5)
+    let extent = 2f32.sqrt() + 0.5f32.sqrt();
+    assert!(rect_eq(
+        bounds.aabb,
+        Rect::from_center_half_size(Vec2::new(10.0, 0.0), Vec2::splat(extent))
+    ));
+    assert!(bounds.obb.center.abs_diff_eq(Vec2::new(10.0, 0.0), EPSILON));
+    assert!(bounds
+        .obb
+        .half_size
+        .abs_diff_eq(Vec2::new(2.0, 1.0), EPSILON));
+    assert!((bounds.obb.rotation.degrees().to_f32() - 45.0).abs() < 1e-3);
+
+    assert!(bounds.contains_point(Vec2::new(11.4, 1.4)));
+    // Inside the aabb corner but outside the rotated rect
+    assert!(!bounds.contains_point(Vec2::new(10.0 + extent - 0.1, extent - 0.1)));
+}
+
+#[test]
+fn offset_rects_follow_the_transform() {
+    let affine = Affine2::from_scale_angle_translation(
+        Vec2::splat(3.0),
+        90f32.to_radians(),
+        Vec2::new(-5.0, 5.0),
+    );
+    let shape = Bounds2D::rect(Vec2::new(4.0, 2.0)).with_offset(Vec2::new(1.0, 0.0));
+    let bounds = WorldBounds2D::from_affine(&shape, affine);
+
+    assert!(rect_eq(bounds.aabb, Rect::new(-8.0, 2.0, -2.0, 14.0)));
+    assert!(bounds.obb.center.abs_diff_eq(Vec2::new(-5.0, 8.0), EPSILON));
+}
+
+#[test]
+fn scaled_circles_are_ellipses() {
+    let affine = Affine2::from_scale_angle_translation(Vec2::new(3.0, 1.0), 0.0, Vec2::ZERO);
+    let bounds = WorldBounds2D::from_affine(&Bounds2D::circle(2.0), affine);
+    assert!(rect_eq(bounds.aabb, Rect::new(-6.0, -2.0, 6.0, 2.0)));
+    assert!(bounds
+        .obb
+        .half_size
+        .abs_diff_eq(Vec2::new(6.0, 2.0), EPSILON));
+
+    let affine =
+        Affine2::from_scale_angle_translation(Vec2::new(3.0, 1.0), 90f32.to_radians(), Vec2::ZERO);
+    let bounds = WorldBounds2D::from_affine(&Bounds2D::circle(2.0), affine);
+    assert!(rect_eq(bounds.aabb, Rect::new(-2.0, -6.0, 2.0, 6.0)));
+    assert!(bounds.contains_point(Vec2::new(0.0, 5.9)));
+    assert!(!bounds.contains_point(Vec2::new(1.9, 5.0)));
+}
+
+#[test]
+fn zero_sized_rects_intersect_on_contact() {
+    let bounds = WorldBounds2D::from_affine(&Bounds2D::rect(Vec2::splat(2.0)), Affine2::IDENTITY);
+    let point = |x: f32, y: f32| Rect::from_center_size(Vec2::new(x, y), Vec2::ZERO);
+
+    assert!(bounds.intersects_rect(point(0.5, 0.5)));
+    assert!(bounds.intersects_rect(point(1.0, 0.0)));
+    assert!(!bounds.intersects_rect(point(1.5, 0.0)));
+    assert!(bounds.intersects_rect(Rect::new(1.0, -3.0, 4.0, 3.0)));
+}
+
+#[test]
+fn world_bounds_track_the_propagated_transform() {
+    let mut app = app();
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(100.0, 0.0),
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            scale: Scale2D::new(2.0, 2.0),
+            ..default()
+        })
+        .id();
+    let child = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(10.0, 0.0),
+                ..default()
+            },
+            Bounds2D::rect(Vec2::new(4.0, 2.0)),
+        ))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    let bounds = *app.world().get::<WorldBounds2D>(child).unwrap();
+    assert!(rect_eq(bounds.aabb, Rect::new(98.0, 16.0, 102.0, 24.0)));
+
+    app.world_mut().get_mut::<Position2D>(parent).unwrap().x = 0.0;
+    app.update();
+    let bounds = *app.world().get::<WorldBounds2D>(child).unwrap();
+    assert!(rect_eq(bounds.aabb, Rect::new(-2.0, 16.0, 2.0, 24.0)));
+
+    app.world_mut().entity_mut(child).remove::<Bounds2D>();
+    app.update();
+    assert!(app.world().get::<WorldBounds2D>(child).is_none());
+}
+
+#[test]
+fn hierarchy_bounds_are_the_union_of_every_descendant() {
+    let mut app = app();
+    let root = app
+        .world_mut()
+        .spawn((SpatialBundle2D::default(), HierarchyBounds2D::default()))
+        .id();
+    let middle = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(10.0, 0.0),
+                ..default()
+            },
+            Bounds2D::rect(Vec2::splat(2.0)),
+            HierarchyBounds2D::default(),
+        ))
+        .set_parent(root)
+        .id();
+    app.world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(0.0, -20.0),
+                ..default()
+            },
+            Bounds2D::circle(1.0),
+        ))
+        .set_parent(middle);
+    let empty = app
+        .world_mut()
+        .spawn((SpatialBundle2D::default(), HierarchyBounds2D::default()))
+        .set_parent(root)
+        .id();
+    // World bounds are inserted with commands, the union sees them a frame later
+    app.update();
+    app.update();
+
+    let union = |entity| app.world().get::<HierarchyBounds2D>(entity).unwrap().0;
+    let expected = Rect::new(9.0, -21.0, 11.0, 1.0);
+    assert!(rect_eq(union(root).unwrap(), expected));
+    assert!(rect_eq(union(middle).unwrap(), expected));
+    assert_eq!(union(empty), None);
+}
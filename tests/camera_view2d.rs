@@ -0,0 +1,204 @@
+mod common;
+
+use bevy::{
+    ecs::system::RunSystemOnce,
+    prelude::*,
+    render::{
+        camera::{camera_system, ManualTextureViews, RenderTarget, Viewport},
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+    window::{PrimaryWindow, WindowCreated, WindowRef, WindowResized, WindowScaleFactorChanged},
+};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-3;
+
+fn app() -> App {
+    let mut app = common::app();
+    // What camera_system needs to fill in viewports without a renderer
+    app.add_event::<WindowCreated>()
+        .add_event::<WindowResized>()
+        .add_event::<WindowScaleFactorChanged>()
+        .add_event::<AssetEvent<Image>>()
+        .init_resource::<Assets<Image>>()
+        .init_resource::<ManualTextureViews>()
+        .add_systems(PostUpdate, camera_system::<OrthographicProjection>);
+    app
+}
+
+fn window(app: &mut App, primary: bool, cursor: Vec2) -> Entity {
+    let mut window = Window::default();
+    window.resolution.set(800.0, 600.0);
+    window.set_cursor_position(Some(cursor));
+    let mut entity = app.world_mut().spawn(window);
+    if primary {
+        entity.insert(PrimaryWindow);
+    }
+    entity.id()
+}
+
+fn camera(app: &mut App, order: isize, target: RenderTarget, x: f32) -> Entity {
+    app.world_mut()
+        .spawn(Camera2dBundle {
+            camera: Camera {
+                order,
+                target,
+                ..default()
+            },
+            transform: Transform::from_xyz(x, 0.0, 0.0),
+            ..default()
+        })
+        .id()
+}
+
+fn cursor_position(app: &mut App) -> Option<Position2D> {
+    app.world_mut()
+        .run_system_once(|cameras: Cameras2D| cameras.cursor_position())
+}
+
+#[test]
+fn synthetic_views_round_trip() {
+    let view = CameraView2D::synthetic(
+        Vec2::new(800.0, 600.0),
+        Position2D::new(100.0, -50.0),
+        Rotation2D::from_f32_degrees(30.0),
+        2.0,
+    );
+    for world in [
+        Position2D::new(100.0, -50.0),
+        Position2D::new(-300.0, 200.0),
+        Position2D::new(1000.0, 1000.0),
+    ] {
+        let back = view.viewport_to_world(view.world_to_viewport(world));
+        assert!(Vec2::from(back).abs_diff_eq(world.into(), EPSILON));
+    }
+    for viewport in [Vec2::ZERO, Vec2::new(400.0, 300.0), Vec2::new(13.0, 587.0)] {
+        let back = view.world_to_viewport(view.viewport_to_world(viewport));
+        assert!(back.abs_diff_eq(viewport, EPSILON));
+    }
+
+    // The viewport centre is the camera position and y points down on screen
+    let center = view.viewport_to_world(Vec2::new(400.0, 300.0));
+    assert!(Vec2::from(center).abs_diff_eq(Vec2::new(100.0, -50.0), EPSILON));
+    let up = Vec2::from(view.viewport_to_world(Vec2::new(400.0, 0.0))) - Vec2::from(center);
+    assert!(up.abs_diff_eq(Vec2::from_angle(120f32.to_radians()) * 600.0, EPSILON));
+    assert!(view.contains_world(Position2D::new(100.0, 500.0)));
+    assert!(!view.contains_world(Position2D::new(100.0, 1000.0)));
+    assert!(view.visible_rect().contains(Vec2::new(-400.0, -100.0)));
+}
+
+#[test]
+fn ndc_corners_map_to_the_viewport_corners() {
+    let view = CameraView2D::new(
+        Rect::new(100.0, 50.0, 300.0, 150.0),
+        Rect::new(-10.0, -5.0, 10.0, 5.0),
+        default(),
+    );
+    assert!(view
+        .ndc_to_viewport(Vec2::new(-1.0, 1.0))
+        .abs_diff_eq(Vec2::new(100.0, 50.0), EPSILON));
+    assert!(view
+        .viewport_to_ndc(Vec2::new(300.0, 150.0))
+        .abs_diff_eq(Vec2::new(1.0, -1.0), EPSILON));
+    assert!(Vec2::from(view.ndc_to_world(Vec2::ONE)).abs_diff_eq(Vec2::new(10.0, 5.0), EPSILON));
+}
+
+#[test]
+fn the_cursor_uses_the_top_camera_on_its_window() {
+    let mut app = app();
+    let primary = window(&mut app, true, Vec2::new(500.0, 300.0));
+    let other = window(&mut app, false, Vec2::ZERO);
+    camera(&mut app, 0, RenderTarget::Window(WindowRef::Primary), 0.0);
+    camera(
+        &mut app,
+        1,
+        RenderTarget::Window(WindowRef::Entity(primary)),
+        1000.0,
+    );
+    // Higher orders that draw elsewhere
+    camera(
+        &mut app,
+        5,
+        RenderTarget::Window(WindowRef::Entity(other)),
+        2000.0,
+    );
+    let image = app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::new_fill(
+            Extent3d {
+                width: 64,
+                height: 64,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0; 4],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ));
+    camera(&mut app, 9, RenderTarget::Image(image), 3000.0);
+    app.update();
+
+    let cursor = cursor_position(&mut app).unwrap();
+    assert!(Vec2::from(cursor).abs_diff_eq(Vec2::new(1100.0, 0.0), EPSILON));
+}
+
+#[test]
+fn the_primary_window_target_follows_the_primary_window() {
+    let mut app = app();
+    window(&mut app, true, Vec2::new(0.0, 0.0));
+    let camera = camera(&mut app, 0, RenderTarget::Window(WindowRef::Primary), 50.0);
+    app.update();
+
+    let cursor = cursor_position(&mut app).unwrap();
+    assert!(Vec2::from(cursor).abs_diff_eq(Vec2::new(-350.0, 300.0), EPSILON));
+    let viewport = app.world_mut().run_system_once(move |cameras: Cameras2D| {
+        cameras.world_to_viewport(camera, Position2D::new(50.0, 0.0))
+    });
+    assert!(viewport
+        .unwrap()
+        .abs_diff_eq(Vec2::new(400.0, 300.0), EPSILON));
+
+    app.world_mut().get_mut::<Camera>(camera).unwrap().is_active = false;
+    assert_eq!(cursor_position(&mut app), None);
+}
+
+#[test]
+fn the_cursor_uses_the_top_camera_whose_viewport_it_is_in() {
+    let mut app = app();
+    let primary = window(&mut app, true, Vec2::new(100.0, 300.0));
+    let full = camera(&mut app, 0, RenderTarget::Window(WindowRef::Primary), 0.0);
+    // A minimap drawn over the top right corner
+    let minimap = camera(
+        &mut app,
+        1,
+        RenderTarget::Window(WindowRef::Primary),
+        1000.0,
+    );
+    app.world_mut().get_mut::<Camera>(minimap).unwrap().viewport = Some(Viewport {
+        physical_position: UVec2::new(600, 0),
+        physical_size: UVec2::new(200, 150),
+        ..default()
+    });
+    app.update();
+
+    let cursor = cursor_position(&mut app).unwrap();
+    assert!(Vec2::from(cursor).abs_diff_eq(Vec2::new(-300.0, 0.0), EPSILON));
+
+    app.world_mut()
+        .get_mut::<Window>(primary)
+        .unwrap()
+        .set_cursor_position(Some(Vec2::new(700.0, 75.0)));
+    let cursor = cursor_position(&mut app).unwrap();
+    assert!(Vec2::from(cursor).abs_diff_eq(Vec2::new(1000.0, 0.0), EPSILON));
+
+    // Outside the minimap nothing is drawn once the full window camera is gone
+    app.world_mut().despawn(full);
+    assert!(cursor_position(&mut app).is_some());
+    app.world_mut()
+        .get_mut::<Window>(primary)
+        .unwrap()
+        .set_cursor_position(Some(Vec2::new(100.0, 300.0)));
+    assert_eq!(cursor_position(&mut app), None);
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use bevy::prelude::*;
+use common::{app, world_position};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-3;
+
+#[test]
+fn concave_polygons_clamp_out_of_their_notches() {
+    // A U with the notch open at the top
+    let region = ClampRegion2D::Polygon(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(30.0, 0.0),
+        Vec2::new(30.0, 30.0),
+        Vec2::new(20.0, 30.0),
+        Vec2::new(20.0, 10.0),
+        Vec2::new(10.0, 10.0),
+        Vec2::new(10.0, 30.0),
+        Vec2::new(0.0, 30.0),
+    ]);
+    for inside in [
+        Vec2::new(5.0, 20.0),
+        Vec2::new(25.0, 25.0),
+        Vec2::new(15.0, 5.0),
+    ] {
+        assert_eq!(region.closest_point(inside), inside);
+    }
+    assert!(region
+        .closest_point(Vec2::new(12.0, 20.0))
+        .abs_diff_eq(Vec2::new(10.0, 20.0), EPSILON));
+    assert!(region
+        .closest_point(Vec2::new(15.0, 12.0))
+        .abs_diff_eq(Vec2::new(15.0, 10.0), EPSILON));
+    assert!(region
+        .closest_point(Vec2::new(40.0, -5.0))
+        .abs_diff_eq(Vec2::new(30.0, 0.0), EPSILON));
+}
+
+#[test]
+fn self_intersecting_polygons_use_the_even_odd_rule() {
+    let star: Vec<Vec2> = (0..5)
+        .map(|index| Vec2::from_angle((index * 144) as f32 * std::f32::consts::PI / 180.0) * 10.0)
+        .collect();
+    let region = ClampRegion2D::Polygon(star);
+    // The middle pentagon is wound over twice, so it counts as outside
+    let closest = region.closest_point(Vec2::ZERO);
+    assert!((closest.length() - 10.0 * 72f32.to_radians().cos()).abs() < EPSILON);
+    // The points of the star are wound over once
+    let tip = Vec2::new(8.0, 0.0);
+    assert_eq!(region.closest_point(tip), tip);
+}
+
+#[test]
+fn soft_clamps_ease_back_inside() {
+    let clamp = ClampPosition2D::circle(Vec2::ZERO, 10.0).soft(2.0);
+    let eased = clamp.apply(Vec2::new(20.0, 0.0), 0.5);
+    assert!((eased.x - (20.0 - 10.0 * (1.0 - (-1.0f32).exp()))).abs() < EPSILON);
+    assert_eq!(eased.y, 0.0);
+    // Inside is left alone however long the step
+    assert_eq!(clamp.apply(Vec2::new(3.0, 4.0), 10.0), Vec2::new(3.0, 4.0));
+    // A stiffness of zero never pulls back
+    let loose = ClampPosition2D::circle(Vec2::ZERO, 10.0).soft(0.0);
+    assert_eq!(loose.apply(Vec2::new(20.0, 0.0), 1.0), Vec2::new(20.0, 0.0));
+}
+
+#[test]
+fn clamps_apply_in_their_space() {
+    let mut app = app();
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(100.0, 0.0),
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            ..default()
+        })
+        .id();
+    let world = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(0.0, 50.0),
+                ..default()
+            },
+            ClampPosition2D::circle(Vec2::new(100.0, 0.0), 20.0).in_world(),
+        ))
+        .set_parent(parent)
+        .id();
+    let local = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(0.0, 50.0),
+                ..default()
+            },
+            ClampPosition2D::rect(Rect::new(-5.0, -5.0, 5.0, 5.0)),
+        ))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    let position = |entity| Vec2::from(*app.world().get::<Position2D>(entity).unwrap());
+    // Up the parent's y axis is towards -x in the world, so the edge of the circle is 20 along it
+    assert!(position(world).abs_diff_eq(Vec2::new(0.0, 20.0), EPSILON));
+    assert!(position(local).abs_diff_eq(Vec2::new(0.0, 5.0), EPSILON));
+    assert!(world_position(&app, world).abs_diff_eq(Vec2::new(80.0, 0.0), EPSILON));
+}
+
+#[test]
+fn world_clamps_on_absolute_positions_stay_in_world_space() {
+    let mut app = app();
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(100.0, 0.0),
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            ..default()
+        })
+        .id();
+    let child = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(30.0, 40.0),
+                p_prop: PositionPropagation::Absolute,
+                ..default()
+            },
+            ClampPosition2D::circle(Vec2::ZERO, 10.0).in_world(),
+        ))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    let position = Vec2::from(*app.world().get::<Position2D>(child).unwrap());
+    assert!(position.abs_diff_eq(Vec2::new(6.0, 8.0), EPSILON));
+    assert!(world_position(&app, child).abs_diff_eq(Vec2::new(6.0, 8.0), EPSILON));
+}
@@ -0,0 +1,50 @@
+// Shared by the integration tests, each of which only uses part of it
+#![allow(dead_code)]
+
+use bevy::{prelude::*, time::TimeUpdateStrategy, transform::TransformPlugin};
+use rantz_spatial2d::prelude::*;
+use std::time::Duration;
+
+pub fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        HierarchyPlugin,
+        TransformPlugin,
+        SpatialPlugin2D,
+    ));
+    app
+}
+
+// Every update after this advances time by delta, the first update only starts the clock
+pub fn manual_time(app: &mut App, delta: f32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        delta,
+    )));
+    app.update();
+}
+
+pub fn named(app: &mut App, name: &str) -> Entity {
+    let world = app.world_mut();
+    world
+        .query::<(Entity, &Name)>()
+        .iter(world)
+        .find(|(_, entity_name)| entity_name.as_str() == name)
+        .map(|(entity, _)| entity)
+        .unwrap()
+}
+
+pub fn position(app: &App, entity: Entity) -> Vec2 {
+    (*app.world().get::<Position2D>(entity).unwrap()).into()
+}
+
+pub fn world_translation(app: &App, entity: Entity) -> Vec3 {
+    app.world()
+        .get::<GlobalTransform>(entity)
+        .unwrap()
+        .translation()
+}
+
+pub fn world_position(app: &App, entity: Entity) -> Vec2 {
+    world_translation(app, entity).truncate()
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "serde")]
+
+use bevy::prelude::*;
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+fn node(name: &str) -> SpatialNode2D {
+    SpatialNode2D {
+        name: Some(name.to_string()),
+        ..default()
+    }
+}
+
+fn document() -> SpatialDocument2D {
+    SpatialDocument2D::new(vec![
+        SpatialNode2D {
+            position: Position2D::new(10.0, -4.0),
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            scale: Scale2D::new(2.0, -1.0),
+            draw_order: DrawOrder::new(3.0),
+            children: vec![
+                SpatialNode2D {
+                    position: Position2D::new(5.0, 0.0),
+                    rotation: Rotation2D::from_f32_degrees(-30.0),
+                    rotation_propagation: RotationPropagation::Absolute,
+                    scale_propagation: ScalePropagation::Absolute,
+                    children: vec![SpatialNode2D {
+                        position_propagation: PositionPropagation::Absolute,
+                        ..node("grandchild")
+                    }],
+                    ..node("child")
+                },
+                node("sibling"),
+            ],
+            ..node("root")
+        },
+        node("second root"),
+    ])
+}
+
+// Rotations go through degrees, so they may come back a rounding error away
+fn assert_same(a: &SpatialNode2D, b: &SpatialNode2D) {
+    assert_eq!(a.name, b.name);
+    assert_eq!(a.position, b.position);
+    assert!(
+        a.rotation
+            .radians()
+            .shortest_to(b.rotation.radians())
+            .to_f32()
+            .abs()
+            < EPSILON,
+        "{:?} != {:?}",
+        a.rotation,
+        b.rotation
+    );
+    assert_eq!(a.scale, b.scale);
+    assert_eq!(a.draw_order, b.draw_order);
+    assert_eq!(a.rotation_propagation, b.rotation_propagation);
+    assert_eq!(a.position_propagation, b.position_propagation);
+    assert_eq!(a.scale_propagation, b.scale_propagation);
+    assert_eq!(a.children.len(), b.children.len());
+    for (a, b) in a.children.iter().zip(&b.children) {
+        assert_same(a, b);
+    }
+}
+
+fn assert_same_document(a: &SpatialDocument2D, b: &SpatialDocument2D) {
+    assert_eq!(a.version, b.version);
+    assert_eq!(a.roots.len(), b.roots.len());
+    for (a, b) in a.roots.iter().zip(&b.roots) {
+        assert_same(a, b);
+    }
+}
+
+#[test]
+fn documents_round_trip_through_the_world() {
+    let mut world = World::new();
+    let original = document();
+    let roots = original.spawn(&mut world);
+    assert_eq!(roots.len(), 2);
+
+    let saved = SpatialDocument2D::from_world(&world, &roots);
+    assert_same_document(&original, &saved);
+}
+
+#[cfg(feature = "ron")]
+mod ron_documents {
+    use super::*;
+
+    #[test]
+    fn nested_documents_round_trip() {
+        let original = document();
+        let text = original.to_ron().unwrap();
+        let loaded = SpatialDocument2D::from_ron(&text).unwrap();
+        assert_same_document(&original, &loaded);
+        // Saving again doesn't drift
+        assert_eq!(loaded.to_ron().unwrap(), text);
+    }
+
+    #[test]
+    fn defaults_are_left_out_and_filled_back_in() {
+        let text = SpatialDocument2D::new(vec![node("bare")]).to_ron().unwrap();
+        assert!(text.contains("version: 1"), "{text}");
+        for field in ["position", "rotation", "scale", "children"] {
+            assert!(!text.contains(field), "{text}");
+        }
+
+        let loaded = SpatialDocument2D::from_ron(
+            "(version: 1, roots: [(position: (y: 4.0), scale: (x: -1.0), rotation: 45.0)])",
+        )
+        .unwrap();
+        let root = &loaded.roots[0];
+        assert_eq!(root.position, Position2D::new(0.0, 4.0));
+        assert_eq!(root.scale, Scale2D::new(-1.0, 1.0));
+        assert!((root.rotation.degrees().to_f32() - 45.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn newer_versions_are_refused() {
+        let text = format!("(version: {})", SPATIAL_DOCUMENT_VERSION + 1);
+        assert!(matches!(
+            SpatialDocument2D::from_ron(&text),
+            Err(SpatialDocumentError::UnsupportedVersion(version))
+                if version == SPATIAL_DOCUMENT_VERSION + 1
+        ));
+        assert!(SpatialDocument2D::from_ron("(version: 1)")
+            .unwrap()
+            .roots
+            .is_empty());
+        assert!(matches!(
+            SpatialDocument2D::from_ron("(roots: [])"),
+            Err(SpatialDocumentError::RonParse(_))
+        ));
+    }
+}
+
+#[cfg(feature = "json")]
+mod json_documents {
+    use super::*;
+
+    #[test]
+    fn nested_documents_round_trip() {
+        let original = document();
+        let text = original.to_json().unwrap();
+        let loaded = SpatialDocument2D::from_json(&text).unwrap();
+        assert_same_document(&original, &loaded);
+        assert_eq!(loaded.to_json().unwrap(), text);
+    }
+
+    #[test]
+    fn rotations_are_written_in_degrees() {
+        let text = SpatialDocument2D::new(vec![SpatialNode2D {
+            rotation: Rotation2D::from_f32_degrees(-90.0),
+            ..default()
+        }])
+        .to_json()
+        .unwrap();
+        assert!(text.contains("\"rotation\": -90.0"), "{text}");
+        assert!(text.contains("\"version\": 1"), "{text}");
+
+        let loaded = SpatialDocument2D::from_json(
+            r#"{"version": 1, "roots": [{"rotation": 180, "position": {"x": 3}, "scale": {"y": 2}}]}"#,
+        )
+        .unwrap();
+        let root = &loaded.roots[0];
+        assert!((root.rotation.degrees().to_f32().abs() - 180.0).abs() < EPSILON);
+        assert_eq!(root.position, Position2D::new(3.0, 0.0));
+        assert_eq!(root.scale, Scale2D::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn newer_versions_are_refused() {
+        assert!(matches!(
+            SpatialDocument2D::from_json(r#"{"version": 99}"#),
+            Err(SpatialDocumentError::UnsupportedVersion(99))
+        ));
+        assert!(matches!(
+            SpatialDocument2D::from_json(r#"{"roots": []}"#),
+            Err(SpatialDocumentError::Json(_))
+        ));
+    }
+}
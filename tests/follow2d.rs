@@ -0,0 +1,178 @@
+mod common;
+
+use bevy::prelude::*;
+use common::{app, world_position};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-3;
+
+fn follow() -> Follow2D {
+    Follow2D::new(Entity::PLACEHOLDER)
+}
+
+#[test]
+fn steps_chase_the_goal() {
+    let goal = Vec2::new(10.0, 0.0);
+    assert_eq!(follow().step(Vec2::ZERO, goal, 0.1), goal);
+
+    let mut lag = follow().with_smoothing(FollowSmoothing2D::Lag { rate: 2.0 });
+    let next = lag.step(Vec2::ZERO, goal, 0.5);
+    assert!(next.abs_diff_eq(goal * (1.0 - (-1.0f32).exp()), EPSILON));
+
+    let mut spring = follow().with_smoothing(FollowSmoothing2D::Spring {
+        stiffness: 10.0,
+        damping: 2.0,
+    });
+    let next = spring.step(Vec2::ZERO, goal, 0.1);
+    assert!(next.abs_diff_eq(Vec2::new(1.0, 0.0), EPSILON));
+    assert!(spring.velocity().abs_diff_eq(Vec2::new(10.0, 0.0), EPSILON));
+}
+
+#[test]
+fn dead_zones_and_max_distance_bound_the_gap() {
+    let goal = Vec2::new(10.0, 0.0);
+    let mut dead_zone = follow().with_dead_zone(3.0);
+    assert_eq!(
+        dead_zone.step(Vec2::new(8.0, 0.0), goal, 0.1),
+        Vec2::new(8.0, 0.0)
+    );
+    assert!(dead_zone
+        .step(Vec2::ZERO, goal, 0.1)
+        .abs_diff_eq(Vec2::new(7.0, 0.0), EPSILON));
+
+    let mut leash = follow()
+        .with_smoothing(FollowSmoothing2D::Lag { rate: 0.001 })
+        .with_max_distance(4.0);
+    assert!(leash
+        .step(Vec2::ZERO, goal, 0.1)
+        .abs_diff_eq(Vec2::new(6.0, 0.0), EPSILON));
+}
+
+#[test]
+fn chains_settle_in_one_frame_whatever_the_spawn_order() {
+    let mut app = app();
+    let leader = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(100.0, 0.0),
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            ..default()
+        })
+        .id();
+    // Spawned before what they follow so entity order can't line them up by accident
+    let last = app.world_mut().spawn(SpatialBundle2D::default()).id();
+    let middle = app.world_mut().spawn(SpatialBundle2D::default()).id();
+    let first = app.world_mut().spawn(SpatialBundle2D::default()).id();
+    app.world_mut().entity_mut(first).insert(
+        Follow2D::new(leader).with_offset(FollowOffset2D::TargetLocal(Vec2::new(10.0, 0.0))),
+    );
+    app.world_mut()
+        .entity_mut(middle)
+        .insert(Follow2D::new(first).with_offset(FollowOffset2D::World(Vec2::new(0.0, 5.0))));
+    app.world_mut().entity_mut(last).insert(
+        Follow2D::new(middle)
+            .with_offset(FollowOffset2D::World(Vec2::new(1.0, 1.0)))
+            .with_lock_axes(false, true),
+    );
+    app.update();
+
+    assert!(world_position(&app, first).abs_diff_eq(Vec2::new(100.0, 10.0), EPSILON));
+    assert!(world_position(&app, middle).abs_diff_eq(Vec2::new(100.0, 15.0), EPSILON));
+    assert!(world_position(&app, last).abs_diff_eq(Vec2::new(101.0, 0.0), EPSILON));
+}
+
+#[test]
+fn followers_inside_a_moving_parent_land_in_world_space() {
+    let mut app = app();
+    let target = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(-20.0, 40.0),
+            ..default()
+        })
+        .id();
+    let parent = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                rotation: Rotation2D::from_f32_degrees(-90.0),
+                scale: Scale2D::new(2.0, 2.0),
+                ..default()
+            },
+            Follow2D::new(target),
+        ))
+        .id();
+    let child = app
+        .world_mut()
+        .spawn((SpatialBundle2D::default(), Follow2D::new(target)))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    assert!(world_position(&app, parent).abs_diff_eq(Vec2::new(-20.0, 40.0), EPSILON));
+    assert!(world_position(&app, child).abs_diff_eq(Vec2::new(-20.0, 40.0), EPSILON));
+}
+
+#[test]
+fn absolute_followers_write_world_positions() {
+    let mut app = app();
+    let target = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(-20.0, 40.0),
+            ..default()
+        })
+        .id();
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(30.0, -10.0),
+            rotation: Rotation2D::from_f32_degrees(45.0),
+            ..default()
+        })
+        .id();
+    let child = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                p_prop: PositionPropagation::Absolute,
+                ..default()
+            },
+            Follow2D::new(target).with_offset(FollowOffset2D::World(Vec2::new(5.0, 0.0))),
+        ))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    let position = Vec2::from(*app.world().get::<Position2D>(child).unwrap());
+    assert!(position.abs_diff_eq(Vec2::new(-15.0, 40.0), EPSILON));
+    assert!(world_position(&app, child).abs_diff_eq(Vec2::new(-15.0, 40.0), EPSILON));
+}
+
+#[test]
+fn cycles_of_followers_still_update() {
+    let mut app = app();
+    // Every follower is also the parent of the next, so each one depends on all before it
+    let mut entities: Vec<Entity> = Vec::new();
+    for index in 0..40 {
+        let mut entity = app.world_mut().spawn(SpatialBundle2D {
+            position: Position2D::new(index as f32, 0.0),
+            ..default()
+        });
+        if let Some(parent) = entities.last() {
+            entity.set_parent(*parent);
+        }
+        entities.push(entity.id());
+    }
+    for (index, entity) in entities.iter().enumerate() {
+        let target = entities[(index + 1) % entities.len()];
+        app.world_mut()
+            .entity_mut(*entity)
+            .insert(Follow2D::new(target));
+    }
+    app.update();
+
+    for entity in entities {
+        assert!(world_position(&app, entity).is_finite());
+    }
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use bevy::prelude::*;
+use common::app;
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-3;
+
+fn corner(speed: f32) -> FollowPath2D {
+    FollowPath2D::new(
+        [
+            Position2D::new(0.0, 0.0),
+            Position2D::new(10.0, 0.0),
+            Position2D::new(10.0, 10.0),
+        ],
+        speed,
+    )
+}
+
+fn advance(path: &mut FollowPath2D, delta: f32) -> Vec<PathEventKind2D> {
+    let mut events = Vec::new();
+    path.advance(delta, |kind| events.push(kind));
+    events
+}
+
+#[test]
+fn changing_the_curve_or_mode_rebuilds_the_path() {
+    let mut path = corner(1.0);
+    assert!((path.length() - 20.0).abs() < EPSILON);
+
+    path.set_mode(PathLoop2D::Loop);
+    assert_eq!(path.mode(), PathLoop2D::Loop);
+    assert!((path.length() - (20.0 + 200f32.sqrt())).abs() < EPSILON);
+
+    let closed = path.length();
+    path.set_curve(PathCurve2D::CatmullRom);
+    assert_eq!(path.curve(), PathCurve2D::CatmullRom);
+    assert!((path.length() - closed).abs() > EPSILON);
+
+    path.set_curve(PathCurve2D::Linear);
+    path.set_mode(PathLoop2D::Once);
+    assert!((path.length() - 20.0).abs() < EPSILON);
+}
+
+#[test]
+fn negative_speed_runs_the_path_backwards() {
+    let mut path = corner(-5.0);
+    assert_eq!(path.position(), Position2D::new(10.0, 10.0));
+
+    assert!(advance(&mut path, 1.0).is_empty());
+    assert!(Vec2::from(path.position()).abs_diff_eq(Vec2::new(10.0, 5.0), EPSILON));
+    assert!(path.tangent().abs_diff_eq(Vec2::NEG_Y, EPSILON));
+
+    assert_eq!(
+        advance(&mut path, 10.0),
+        vec![
+            PathEventKind2D::Waypoint(1),
+            PathEventKind2D::Waypoint(0),
+            PathEventKind2D::Finished,
+        ]
+    );
+    assert!(path.is_finished());
+    assert_eq!(path.position(), Position2D::new(0.0, 0.0));
+}
+
+#[test]
+fn negative_speed_ping_pongs_back_to_the_last_point() {
+    let mut path = corner(-10.0).with_mode(PathLoop2D::PingPong);
+    assert_eq!(
+        advance(&mut path, 4.0),
+        vec![
+            PathEventKind2D::Waypoint(1),
+            PathEventKind2D::Waypoint(0),
+            PathEventKind2D::Waypoint(1),
+            PathEventKind2D::Waypoint(2),
+            PathEventKind2D::Looped,
+        ]
+    );
+    assert_eq!(path.position(), Position2D::new(10.0, 10.0));
+
+    advance(&mut path, 1.5);
+    assert!(Vec2::from(path.position()).abs_diff_eq(Vec2::new(5.0, 0.0), EPSILON));
+    assert!(path.tangent().abs_diff_eq(Vec2::NEG_X, EPSILON));
+
+    // Flipping the sign mid way turns it around where it stands
+    path.speed = 10.0;
+    advance(&mut path, 0.25);
+    assert!(Vec2::from(path.position()).abs_diff_eq(Vec2::new(7.5, 0.0), EPSILON));
+    assert!(path.tangent().abs_diff_eq(Vec2::X, EPSILON));
+}
+
+#[test]
+fn facing_along_respects_rotation_propagation() {
+    let mut app = app();
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            ..default()
+        })
+        .id();
+    let mut spawn_child = |r_prop: RotationPropagation| {
+        app.world_mut()
+            .spawn((
+                SpatialBundle2D {
+                    r_prop,
+                    ..default()
+                },
+                corner(1.0).facing_along(),
+            ))
+            .set_parent(parent)
+            .id()
+    };
+    let relative = spawn_child(RotationPropagation::Relative);
+    let absolute = spawn_child(RotationPropagation::Absolute);
+    app.update();
+
+    let rotation = |entity| {
+        app.world()
+            .get::<Rotation2D>(entity)
+            .unwrap()
+            .degrees()
+            .to_f32()
+    };
+    // The path runs along the parent's x axis, which points up in the world
+    assert!(rotation(relative).abs() < EPSILON);
+    assert!((rotation(absolute) - 90.0).abs() < EPSILON);
+    for entity in [relative, absolute] {
+        let (_, world, _) = app
+            .world()
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .to_scale_rotation_translation();
+        let facing = world.to_euler(EulerRot::ZYX).0.to_degrees();
+        assert!((facing - 90.0).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "testing")]
+
+use rantz_spatial2d::prelude::*;
+
+const TOLERANCE: f32 = 1e-3;
+
+fn golden(name: &str) -> String {
+    format!(
+        "{}/tests/fixtures/golden/{name}",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn arm() -> TestHierarchy2D {
+    TestHierarchy2D::new().with_root(
+        TestNode2D::new("body")
+            .at(100.0, 50.0)
+            .rotated(90.0)
+            .scaled(2.0, 2.0)
+            .with_child(
+                TestNode2D::new("upper arm")
+                    .at(10.0, 0.0)
+                    .rotated(-45.0)
+                    .with_draw_order(1.0)
+                    .with_children([
+                        TestNode2D::new("hand").at(5.0, 0.0).scaled(1.0, -1.0),
+                        TestNode2D::new("badge")
+                            .at(0.0, 5.0)
+                            .rotated(30.0)
+                            .absolute_rotation()
+                            .absolute_scale(),
+                        TestNode2D::new("cursor")
+                            .at(-20.0, 10.0)
+                            .absolute_position(),
+                    ]),
+            ),
+    )
+}
+
+#[test]
+fn arm_matches_golden_file() {
+    arm()
+        .run(2)
+        .assert_golden_file(golden("arm.txt"), TOLERANCE);
+}
+
+#[test]
+fn propagation_is_stable_across_frames() {
+    let hierarchy = arm();
+    let first = hierarchy.run(1);
+    assert!(first.diff(&hierarchy.run(10), TOLERANCE).is_empty());
+    assert_eq!(first.entries.len(), 5);
+}
+
+#[test]
+fn inline_snapshot_matches() {
+    TestHierarchy2D::new()
+        .with_root(
+            TestNode2D::new("root")
+                .rotated(180.0)
+                .with_child(TestNode2D::new("child").at(1.0, 0.0)),
+        )
+        .run(1)
+        .assert_matches(
+            "
+            # rotation wraps, so -180 and 180 are the same
+            root position=(0, 0) rotation=-180 scale=(1, 1) z=0
+            root/child position=(-1, 0) rotation=180 scale=(1, 1) z=0
+            ",
+            TOLERANCE,
+        );
+}
+
+#[test]
+fn differences_are_reported() {
+    let snapshot = arm().run(1);
+    let mut expected = snapshot.clone();
+    expected.entries[0].position.x += 1.0;
+    expected.entries[1].rotation += 0.5 * TOLERANCE;
+    expected.entries[2].path = "body/gone".into();
+
+    let differences = snapshot.diff(&expected, TOLERANCE);
+    assert_eq!(differences.len(), 3, "{differences:?}");
+    assert!(differences[0].starts_with("body: position is"));
+    assert_eq!(differences[1], "body/gone: missing");
+    assert!(differences[2].ends_with(": unexpected"));
+
+    assert!(matches!(
+        SpatialSnapshot2D::parse("body position=(1, 2) size=3"),
+        Err(SnapshotError2D::Parse { line: 1, .. })
+    ));
+}
+
+#[test]
+fn missing_golden_files_fail_instead_of_being_written() {
+    // Blessing is supposed to write them
+    if std::env::var_os(SNAPSHOT_BLESS_VAR).is_some() {
+        return;
+    }
+    let path = std::env::temp_dir().join(format!("spatial2d-missing-{}", std::process::id()));
+    let snapshot = arm().run(1);
+    let message = |result: std::thread::Result<()>| {
+        let payload = result.unwrap_err();
+        payload
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    let file = message(std::panic::catch_unwind(|| {
+        snapshot.assert_golden_file(&path, TOLERANCE)
+    }));
+    assert!(file.contains("is missing"), "{file}");
+    assert!(file.contains(SNAPSHOT_BLESS_VAR), "{file}");
+    let text = message(std::panic::catch_unwind(|| {
+        assert_golden_text(&path, "anything")
+    }));
+    assert!(text.contains("is missing"), "{text}");
+    assert!(!path.exists());
+}
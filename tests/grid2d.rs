@@ -0,0 +1,124 @@
+mod common;
+
+use bevy::prelude::*;
+use common::{app, world_position};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+fn local_translation(app: &App, entity: Entity) -> Vec2 {
+    app.world()
+        .get::<Transform>(entity)
+        .unwrap()
+        .translation
+        .truncate()
+}
+
+#[test]
+fn cells_round_trip_through_a_rotated_grid() {
+    let grid = Grid2D::new(Vec2::new(2.0, 4.0))
+        .with_origin(Position2D::new(10.0, 0.0))
+        .with_rotation(Rotation2D::from_f32_degrees(90.0));
+
+    let cell = GridCoord::new(1, -2);
+    let center = grid.cell_center(cell);
+    assert!(Vec2::from(center).abs_diff_eq(Vec2::new(16.0, 3.0), EPSILON));
+    assert_eq!(grid.world_to_cell(center), cell);
+    assert!(Vec2::from(grid.cell_corner(cell)).abs_diff_eq(Vec2::new(18.0, 2.0), EPSILON));
+    assert_eq!(
+        grid.snap(Position2D::new(15.1, 3.9), CellAnchor::Center),
+        center
+    );
+    assert!(
+        Vec2::from(grid.snap(Position2D::new(17.7, 2.2), CellAnchor::Corner))
+            .abs_diff_eq(Vec2::new(18.0, 2.0), EPSILON)
+    );
+}
+
+#[test]
+fn roots_snap_to_the_global_grid() {
+    let mut app = app();
+    app.insert_resource(Grid2D::square(10.0));
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(13.0, -7.0),
+                ..default()
+            },
+            SnapToGrid::default(),
+        ))
+        .id();
+    app.update();
+
+    assert!(world_position(&app, entity).abs_diff_eq(Vec2::new(15.0, -5.0), EPSILON));
+    // Position2D itself is left alone
+    assert_eq!(
+        *app.world().get::<Position2D>(entity).unwrap(),
+        Position2D::new(13.0, -7.0)
+    );
+}
+
+#[test]
+fn children_snap_in_their_parent_grid() {
+    let mut app = app();
+    app.insert_resource(Grid2D::square(100.0));
+    let parent = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(50.0, 50.0),
+                rotation: Rotation2D::from_f32_degrees(90.0),
+                scale: Scale2D::new(2.0, 2.0),
+                ..default()
+            },
+            Grid2D::square(4.0),
+        ))
+        .id();
+    let child = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(5.0, 1.0),
+                ..default()
+            },
+            SnapToGrid {
+                anchor: CellAnchor::Corner,
+            },
+        ))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    assert!(local_translation(&app, child).abs_diff_eq(Vec2::new(4.0, 0.0), EPSILON));
+    assert!(world_position(&app, child).abs_diff_eq(Vec2::new(50.0, 58.0), EPSILON));
+}
+
+#[test]
+fn children_ignore_the_global_grid() {
+    let mut app = app();
+    app.insert_resource(Grid2D::square(10.0));
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(3.0, 3.0),
+            rotation: Rotation2D::from_f32_degrees(30.0),
+            ..default()
+        })
+        .id();
+    let child = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(7.0, 2.0),
+                ..default()
+            },
+            SnapToGrid::default(),
+        ))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    // Snapping the parent local translation to a world grid would land off the grid anyway
+    assert!(local_translation(&app, child).abs_diff_eq(Vec2::new(7.0, 2.0), EPSILON));
+}
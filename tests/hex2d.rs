@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+#[test]
+fn rings_hold_every_hex_at_that_distance_once() {
+    let center = HexCoord::new(2, -3);
+    assert_eq!(center.ring(0), vec![center]);
+    for radius in 1..5 {
+        let ring = center.ring(radius);
+        assert_eq!(ring.len(), 6 * radius as usize);
+        assert!(ring.iter().all(|hex| hex.distance(center) == radius));
+        // Walking the ring only ever steps to a neighbour
+        for (hex, next) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+            assert_eq!(hex.distance(*next), 1);
+        }
+        let mut unique = ring.clone();
+        unique.sort_by_key(|hex| (hex.q, hex.r));
+        unique.dedup();
+        assert_eq!(unique.len(), ring.len());
+    }
+}
+
+#[test]
+fn spirals_fill_the_hexagon_from_the_inside_out() {
+    let spiral = HexCoord::ZERO.spiral(3);
+    // 1 + 6 + 12 + 18
+    assert_eq!(spiral.len(), 37);
+    assert_eq!(spiral[0], HexCoord::ZERO);
+    assert!(spiral
+        .windows(2)
+        .all(|pair| pair[0].length() <= pair[1].length()));
+    assert!(spiral.iter().all(|hex| hex.length() <= 3));
+}
+
+#[test]
+fn rounding_picks_the_containing_hex() {
+    assert_eq!(HexCoord::round(Vec2::new(0.1, 0.1)), HexCoord::ZERO);
+    assert_eq!(HexCoord::round(Vec2::new(0.6, 0.3)), HexCoord::new(1, 0));
+    // Rounding each axis on its own gives (1, 1), which breaks q + r + s = 0
+    assert_eq!(HexCoord::round(Vec2::new(0.7, 0.6)), HexCoord::new(1, 0));
+    assert_eq!(HexCoord::round(Vec2::new(-1.4, 0.7)), HexCoord::new(-2, 1));
+    for hex in HexCoord::new(-1, 2).spiral(2) {
+        assert_eq!(HexCoord::round(Vec2::new(hex.q as f32, hex.r as f32)), hex);
+    }
+}
+
+#[test]
+fn lines_step_one_hex_at_a_time() {
+    let start = HexCoord::new(-2, 1);
+    let end = HexCoord::new(3, -1);
+    let line = start.line_to(end);
+    assert_eq!(line.len(), start.distance(end) as usize + 1);
+    assert_eq!(line.first(), Some(&start));
+    assert_eq!(line.last(), Some(&end));
+    assert!(line.windows(2).all(|pair| pair[0].distance(pair[1]) == 1));
+    assert_eq!(start.line_to(start), vec![start]);
+}
+
+#[test]
+fn layouts_round_trip_and_snap() {
+    for layout in [
+        HexLayout::pointy(10.0).with_origin(Position2D::new(5.0, -5.0)),
+        HexLayout::flat(3.0),
+        HexLayout::new(HexOrientation::PointyTop, Vec2::new(4.0, 2.0)),
+    ] {
+        for hex in HexCoord::new(1, -1).spiral(3) {
+            let center = layout.hex_to_world(hex);
+            assert_eq!(layout.world_to_hex(center), hex);
+            // Just inside every corner still belongs to this hex
+            for corner in layout.corners(hex) {
+                let inside = Vec2::from(center).lerp(corner.into(), 0.9);
+                assert_eq!(layout.world_to_hex(inside.into()), hex);
+                assert_eq!(layout.snap(inside.into()), center);
+            }
+        }
+    }
+}
+
+#[test]
+fn directions_point_at_their_neighbours() {
+    let pointy = HexLayout::pointy(1.0);
+    for direction in PointyHexDirection::ALL {
+        let neighbour = Vec2::from(pointy.hex_to_world(HexCoord::ZERO.neighbor(direction)));
+        assert!(Vec2::from(direction).abs_diff_eq(neighbour.normalize(), EPSILON));
+        assert_eq!(direction.rotate_ccw().rotate_cw(), direction);
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(
+            HexCoord::from(direction.opposite()),
+            -HexCoord::from(direction)
+        );
+        assert_eq!(
+            PointyHexDirection::from(Rotation2D::from(direction)),
+            direction
+        );
+    }
+    let flat = HexLayout::flat(1.0);
+    for direction in FlatHexDirection::ALL {
+        let neighbour = Vec2::from(flat.hex_to_world(HexCoord::ZERO.neighbor(direction)));
+        assert!(Vec2::from(direction).abs_diff_eq(neighbour.normalize(), EPSILON));
+        assert_eq!(
+            FlatHexDirection::from(Rotation2D::from(direction)),
+            direction
+        );
+    }
+}
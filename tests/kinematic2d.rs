@@ -0,0 +1,293 @@
+mod common;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use common::{manual_time, position, world_position};
+use rantz_spatial2d::prelude::*;
+use std::time::Duration;
+
+const EPSILON: f32 = 1e-3;
+const DELTA: f32 = 0.015625;
+
+fn app(kinematics: KinematicPlugin2D) -> App {
+    let mut app = common::app();
+    app.add_plugins(kinematics);
+    manual_time(&mut app, DELTA);
+    app
+}
+
+fn step(app: &mut App, steps: usize) {
+    for _ in 0..steps {
+        app.update();
+    }
+}
+
+#[test]
+fn velocity_moves_position_by_velocity_times_time() {
+    let mut app = app(KinematicPlugin2D::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D::default(),
+            Velocity2D::new(4.0, -2.0),
+            AngularVelocity2D::from_degrees(90.0),
+            ScaleVelocity2D::splat(0.5),
+        ))
+        .id();
+    step(&mut app, 64);
+
+    assert!(position(&app, entity).abs_diff_eq(Vec2::new(4.0, -2.0), EPSILON));
+    let rotation = app.world().get::<Rotation2D>(entity).unwrap();
+    assert!((rotation.degrees().to_f32() - 90.0).abs() < 0.01);
+    let scale = app.world().get::<Scale2D>(entity).unwrap();
+    assert!(Vec2::new(scale.x, scale.y).abs_diff_eq(Vec2::splat(1.5), EPSILON));
+}
+
+#[test]
+fn velocity_frames_follow_the_hierarchy() {
+    let mut app = app(KinematicPlugin2D::default());
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            ..default()
+        })
+        .id();
+    let spawn_child = |app: &mut App, frame: VelocityFrame2D| {
+        app.world_mut()
+            .spawn((
+                SpatialBundle2D {
+                    rotation: Rotation2D::from_f32_degrees(90.0),
+                    ..default()
+                },
+                Velocity2D::new(1.0, 0.0),
+                frame,
+            ))
+            .set_parent(parent)
+            .id()
+    };
+    let in_parent = spawn_child(&mut app, VelocityFrame2D::Parent);
+    let local = spawn_child(&mut app, VelocityFrame2D::Local);
+    let world = spawn_child(&mut app, VelocityFrame2D::World);
+    step(&mut app, 64);
+
+    // All in the parent's space, which is turned a quarter left of the world
+    assert!(position(&app, in_parent).abs_diff_eq(Vec2::new(1.0, 0.0), EPSILON));
+    assert!(position(&app, local).abs_diff_eq(Vec2::new(0.0, 1.0), EPSILON));
+    assert!(position(&app, world).abs_diff_eq(Vec2::new(0.0, -1.0), EPSILON));
+}
+
+// Absolute positions are world values, so every frame resolves to world space
+#[test]
+fn absolute_children_move_in_world_space() {
+    let mut app = app(KinematicPlugin2D::default());
+    app.insert_resource(Gravity2D::new(0.0, -10.0));
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(10.0, 5.0),
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            ..default()
+        })
+        .id();
+    let spawn_child = |app: &mut App, frame: VelocityFrame2D, gravity_scale: f32| {
+        app.world_mut()
+            .spawn((
+                SpatialBundle2D {
+                    p_prop: PositionPropagation::Absolute,
+                    ..default()
+                },
+                KinematicBundle2D {
+                    velocity: Velocity2D::new(1.0, 0.0),
+                    body: KinematicBody2D {
+                        gravity_scale,
+                        ..default()
+                    },
+                    ..default()
+                },
+                frame,
+            ))
+            .set_parent(parent)
+            .id()
+    };
+    let in_parent = spawn_child(&mut app, VelocityFrame2D::Parent, 0.0);
+    let local = spawn_child(&mut app, VelocityFrame2D::Local, 0.0);
+    let world = spawn_child(&mut app, VelocityFrame2D::World, 0.0);
+    let falling = spawn_child(&mut app, VelocityFrame2D::Parent, 1.0);
+    step(&mut app, 64);
+
+    assert!(position(&app, in_parent).abs_diff_eq(Vec2::new(1.0, 0.0), EPSILON));
+    // Local still turns with the parent's rotation, only the position ignores it
+    assert!(position(&app, local).abs_diff_eq(Vec2::new(0.0, 1.0), EPSILON));
+    assert!(position(&app, world).abs_diff_eq(Vec2::new(1.0, 0.0), EPSILON));
+    let velocity = *app.world().get::<Velocity2D>(falling).unwrap();
+    assert!(Vec2::from(velocity).abs_diff_eq(Vec2::new(1.0, -10.0), 0.01));
+    for entity in [in_parent, local, world, falling] {
+        assert!(world_position(&app, entity).abs_diff_eq(position(&app, entity), EPSILON));
+    }
+}
+
+#[test]
+fn damping_decays_exponentially() {
+    let mut app = app(KinematicPlugin2D::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D::default(),
+            Velocity2D::new(10.0, 0.0),
+            AngularVelocity2D::from_radians(2.0),
+            Damping2D::new(0.5, 1.0, 0.0),
+        ))
+        .id();
+    step(&mut app, 128);
+
+    let velocity = app.world().get::<Velocity2D>(entity).unwrap();
+    assert!((velocity.x - 10.0 * (-1.0f32).exp()).abs() < EPSILON);
+    let angular = app.world().get::<AngularVelocity2D>(entity).unwrap();
+    assert!((angular.to_f32() - 2.0 * (-2.0f32).exp()).abs() < EPSILON);
+    // Every step moves by the already damped velocity
+    let decay = (-0.5 * DELTA).exp();
+    let travelled = 10.0 * DELTA * decay * (1.0 - decay.powi(128)) / (1.0 - decay);
+    assert!((position(&app, entity).x - travelled).abs() < EPSILON);
+}
+
+#[test]
+fn max_speed_clamps_linear_and_angular_velocity() {
+    let mut app = app(KinematicPlugin2D::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D::default(),
+            Velocity2D::new(30.0, 40.0),
+            AngularVelocity2D::from_radians(-10.0),
+            MaxSpeed2D::new(5.0, Radians::from_f32(1.0)),
+        ))
+        .id();
+    step(&mut app, 64);
+
+    let velocity = *app.world().get::<Velocity2D>(entity).unwrap();
+    assert!(Vec2::from(velocity).abs_diff_eq(Vec2::new(3.0, 4.0), EPSILON));
+    assert!(position(&app, entity).abs_diff_eq(Vec2::new(3.0, 4.0), EPSILON));
+    let angular = app.world().get::<AngularVelocity2D>(entity).unwrap();
+    assert_eq!(angular.to_f32(), -1.0);
+}
+
+#[test]
+fn scale_velocity_never_reaches_zero() {
+    let mut app = app(KinematicPlugin2D::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                scale: Scale2D::new(DELTA, 1.0),
+                ..default()
+            },
+            ScaleVelocity2D::new(-1.0, -1.0),
+        ))
+        .id();
+    step(&mut app, 1);
+
+    let scale = app.world().get::<Scale2D>(entity).unwrap();
+    assert_eq!(scale.x, DELTA);
+    assert!((scale.y - (1.0 - DELTA)).abs() < EPSILON);
+}
+
+fn body(app: &mut App, velocity: Vec2) -> Entity {
+    app.world_mut()
+        .spawn((
+            SpatialBundle2D::default(),
+            KinematicBundle2D {
+                velocity: velocity.into(),
+                acceleration: Acceleration2D::new(3.0, 0.0),
+                ..default()
+            },
+        ))
+        .id()
+}
+
+fn falling(integrator: Integrator2D) -> App {
+    let mut app = app(KinematicPlugin2D::fixed());
+    app.insert_resource(integrator)
+        .insert_resource(Gravity2D::new(0.0, -10.0));
+    app
+}
+
+#[test]
+fn velocity_verlet_matches_constant_acceleration() {
+    let mut app = falling(Integrator2D::VelocityVerlet);
+    let entity = body(&mut app, Vec2::new(1.0, 5.0));
+    step(&mut app, 64);
+
+    // x = v t + a t^2 / 2 with a = (3, -10) over one second
+    assert!(position(&app, entity).abs_diff_eq(Vec2::new(2.5, 0.0), EPSILON));
+    let velocity = *app.world().get::<Velocity2D>(entity).unwrap();
+    assert!(Vec2::from(velocity).abs_diff_eq(Vec2::new(4.0, -5.0), EPSILON));
+}
+
+#[test]
+fn semi_implicit_euler_leads_by_half_a_step() {
+    let mut app = falling(Integrator2D::SemiImplicitEuler);
+    let entity = body(&mut app, Vec2::new(1.0, 5.0));
+    step(&mut app, 64);
+
+    // Each step moves with the velocity from the end of the step, a t dt / 2 ahead
+    let lead = Vec2::new(3.0, -10.0) * DELTA * 0.5;
+    assert!(position(&app, entity).abs_diff_eq(Vec2::new(2.5, 0.0) + lead, EPSILON));
+    let velocity = *app.world().get::<Velocity2D>(entity).unwrap();
+    assert!(Vec2::from(velocity).abs_diff_eq(Vec2::new(4.0, -5.0), EPSILON));
+}
+
+#[test]
+fn fixed_steps_do_not_depend_on_the_frame_rate() {
+    for integrator in [
+        Integrator2D::SemiImplicitEuler,
+        Integrator2D::VelocityVerlet,
+    ] {
+        let mut every_step = falling(integrator);
+        let mut every_other_step = falling(integrator);
+        every_other_step.insert_resource(TimeUpdateStrategy::ManualDuration(
+            Duration::from_secs_f32(DELTA * 2.0),
+        ));
+        let a = body(&mut every_step, Vec2::new(-2.0, 7.0));
+        let b = body(&mut every_other_step, Vec2::new(-2.0, 7.0));
+        step(&mut every_step, 40);
+        step(&mut every_other_step, 20);
+
+        assert_eq!(position(&every_step, a), position(&every_other_step, b));
+    }
+}
+
+#[test]
+fn forces_and_impulses_only_last_one_step() {
+    let mut app = app(KinematicPlugin2D::fixed());
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D::default(),
+            KinematicBundle2D {
+                body: KinematicBody2D::new(2.0).with_inertia(4.0),
+                ..default()
+            },
+        ))
+        .id();
+    let mut forces = app.world_mut().get_mut::<Forces2D>(entity).unwrap();
+    forces.apply_force(Vec2::new(128.0, 0.0));
+    forces.apply_torque(256.0);
+    forces.apply_impulse(Vec2::new(0.0, 6.0));
+    forces.apply_angular_impulse(2.0);
+    step(&mut app, 1);
+
+    assert_eq!(
+        *app.world().get::<Forces2D>(entity).unwrap(),
+        Forces2D::default()
+    );
+    // force / mass * DELTA plus impulse / mass
+    let expected = Vec2::new(1.0, 3.0);
+    let angular = 1.0 + 0.5;
+    for _ in 0..3 {
+        let velocity = *app.world().get::<Velocity2D>(entity).unwrap();
+        assert!(Vec2::from(velocity).abs_diff_eq(expected, EPSILON));
+        let angular_velocity = app.world().get::<AngularVelocity2D>(entity).unwrap();
+        assert!((angular_velocity.to_f32() - angular).abs() < EPSILON);
+        step(&mut app, 1);
+    }
+}
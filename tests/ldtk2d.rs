@@ -0,0 +1,203 @@
+#![cfg(feature = "ldtk")]
+
+mod common;
+
+use bevy::prelude::*;
+use common::{app, named, world_translation};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/ldtk/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn spawn(app: &mut App, document: &LdtkDocument2D) {
+    document.spawn(app.world_mut());
+    app.update();
+}
+
+fn assert_close(a: Vec3, b: Vec3) {
+    assert!(a.abs_diff_eq(b, EPSILON), "{a} != {b}");
+}
+
+#[test]
+fn levels_and_layers_are_placed_y_up() {
+    let mut app = app();
+    spawn(
+        &mut app,
+        &LdtkDocument2D::load(fixture("project.ldtk")).unwrap(),
+    );
+
+    // Bottom left corners, worldY is the top edge in LDtk
+    let level_0 = named(&mut app, "Level_0");
+    let level_1 = named(&mut app, "Level_1");
+    assert_close(world_translation(&app, level_0), Vec3::new(0.0, -48.0, 0.0));
+    assert_close(world_translation(&app, level_1), Vec3::new(64.0, 0.0, 1.0));
+
+    // The first LDtk layer draws on top
+    let entities = named(&mut app, "Entities");
+    let walls = named(&mut app, "Walls");
+    let ground = named(&mut app, "Ground");
+    let z = |app: &mut App, entity| world_translation(app, entity).z;
+    assert!(z(&mut app, entities) > z(&mut app, walls));
+    assert!(z(&mut app, walls) > z(&mut app, ground));
+    assert!(z(&mut app, entities) < z(&mut app, level_1));
+    assert_close(world_translation(&app, ground), Vec3::new(8.0, -52.0, 0.0));
+    assert_eq!(
+        app.world().get::<Visibility>(ground),
+        Some(&Visibility::Hidden)
+    );
+}
+
+#[test]
+fn entities_are_placed_on_their_pivot() {
+    let mut app = app();
+    spawn(
+        &mut app,
+        &LdtkDocument2D::load(fixture("project.ldtk")).unwrap(),
+    );
+
+    let player = named(&mut app, "Player");
+    let position = world_translation(&app, player);
+    assert_close(position.truncate().extend(0.0), Vec3::new(24.0, -40.0, 0.0));
+
+    // Bottom centre pivot, so the bounds stand on the position
+    let bounds = app.world().get::<WorldBounds2D>(player).unwrap();
+    assert!((bounds.aabb.min.y - position.y).abs() < EPSILON);
+    assert!((bounds.aabb.center().x - position.x).abs() < EPSILON);
+    let entity = app.world().get::<LdtkEntity2D>(player).unwrap();
+    assert_eq!(entity.anchor, Vec2::new(0.0, -0.5));
+    assert_eq!(entity.tags, vec!["actor".to_string()]);
+
+    // Top left pivot
+    let chest = named(&mut app, "Chest");
+    let bounds = app.world().get::<WorldBounds2D>(chest).unwrap();
+    assert_close(
+        bounds.aabb.min.extend(0.0),
+        Vec3::new(48.0, -48.0 + 16.0, 0.0),
+    );
+}
+
+#[test]
+fn int_grid_cells_line_up_with_the_layer_grid() {
+    let mut app = app();
+    spawn(
+        &mut app,
+        &LdtkDocument2D::load(fixture("project.ldtk")).unwrap(),
+    );
+
+    let walls = named(&mut app, "Walls");
+    let grid = *app.world().get::<Grid2D>(walls).unwrap();
+    let world = app.world_mut();
+    let mut cells: Vec<(GridCoord, i32, Position2D)> = world
+        .query::<(&GridCoord, &LdtkIntCell2D, &Position2D)>()
+        .iter(world)
+        .map(|(coord, value, position)| (*coord, value.0, *position))
+        .collect();
+    cells.sort_by_key(|(coord, ..)| (coord.y, coord.x));
+
+    let expected = [
+        (GridCoord::new(0, 0), 2),
+        (GridCoord::new(1, 0), 2),
+        (GridCoord::new(0, 2), 1),
+        (GridCoord::new(3, 2), 1),
+    ];
+    assert_eq!(cells.len(), expected.len());
+    for ((coord, value, position), (expected_coord, expected_value)) in cells.iter().zip(expected) {
+        assert_eq!((*coord, *value), (expected_coord, expected_value));
+        assert_eq!(grid.cell_center(*coord), *position);
+    }
+}
+
+#[test]
+fn tiles_keep_their_flips_and_stacking() {
+    let mut app = app();
+    spawn(
+        &mut app,
+        &LdtkDocument2D::load(fixture("project.ldtk")).unwrap(),
+    );
+
+    let world = app.world_mut();
+    let mut tiles: Vec<(LdtkTile2D, Scale2D, Vec3)> = world
+        .query::<(&LdtkTile2D, &Scale2D, &GlobalTransform)>()
+        .iter(world)
+        .map(|(tile, scale, transform)| (*tile, *scale, transform.translation()))
+        .collect();
+    tiles.sort_by_key(|(tile, ..)| tile.id);
+
+    let (wall, _, _) = tiles[1];
+    assert!(wall.flip_x && !wall.flip_y);
+    let (bottom, bottom_scale, bottom_position) = tiles[0];
+    let (top, _, top_position) = tiles[2];
+    assert!(bottom.flip_y);
+    assert_eq!((bottom_scale.x, bottom_scale.y), (1.0, -1.0));
+    assert_eq!(top.source, UVec2::new(32, 0));
+    assert_close(
+        bottom_position.truncate().extend(0.0),
+        Vec3::new(8.0 + 24.0, -52.0 + 24.0, 0.0),
+    );
+    assert!(top_position.z > bottom_position.z);
+}
+
+#[test]
+fn custom_fields_become_components() {
+    let mut app = app();
+    spawn(
+        &mut app,
+        &LdtkDocument2D::load(fixture("project.ldtk")).unwrap(),
+    );
+
+    let player = named(&mut app, "Player");
+    let fields = app.world().get::<LdtkFields2D>(player).unwrap();
+    assert_eq!(fields.int("hp"), Some(10));
+    assert_eq!(fields.float("speed"), Some(1.5));
+    assert_eq!(fields.string("class"), Some("Mage"));
+    assert_eq!(fields.color("tint"), Some(Color::srgb(1.0, 0.0, 0.0)));
+    assert_eq!(fields.point("target"), Some(GridCoord::new(3, 2)));
+    assert_eq!(fields.entity_ref("friend"), Some("chest"));
+    assert_eq!(fields.get("missing"), Some(&LdtkValue2D::Null));
+    assert_eq!(
+        fields.field("path").unwrap().values,
+        vec![
+            LdtkValue2D::Point(GridCoord::new(0, 2)),
+            LdtkValue2D::Point(GridCoord::new(1, 0)),
+        ]
+    );
+
+    let level = named(&mut app, "Level_0");
+    let fields = app.world().get::<LdtkFields2D>(level).unwrap();
+    assert_eq!(fields.string("music"), Some("calm"));
+}
+
+#[test]
+fn external_levels_are_loaded_next_to_the_project() {
+    let text = std::fs::read_to_string(fixture("external.ldtk")).unwrap();
+    assert!(matches!(
+        LdtkDocument2D::from_json(&text),
+        Err(LdtkImportError::ExternalLevels)
+    ));
+
+    let document = LdtkDocument2D::load(fixture("external.ldtk"))
+        .unwrap()
+        .with_pixels_per_unit(16.0);
+    assert_eq!(
+        document.level_identifiers().collect::<Vec<_>>(),
+        ["Outside"]
+    );
+    let mut app = app();
+    let level = document.spawn_level(app.world_mut(), "Outside").unwrap();
+    app.update();
+
+    assert_eq!(
+        app.world()
+            .get::<LdtkLevel2D>(level)
+            .map(|level| level.size),
+        Some(Vec2::splat(2.0))
+    );
+    let exit = named(&mut app, "Exit");
+    assert_close(
+        world_translation(&app, exit).truncate().extend(0.0),
+        Vec3::new(0.5, -0.5, 0.0),
+    );
+}
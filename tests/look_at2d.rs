@@ -0,0 +1,108 @@
+mod common;
+
+use bevy::prelude::*;
+use common::app;
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-3;
+
+fn degrees(degrees: f32) -> Radians {
+    Radians::from_f32(degrees.to_radians())
+}
+
+fn assert_degrees(actual: Radians, expected: f32) {
+    let difference = (actual.to_f32().to_degrees() - expected).rem_euclid(360.0);
+    assert!(
+        difference.min(360.0 - difference) < EPSILON,
+        "{} != {expected}",
+        actual.to_f32().to_degrees()
+    );
+}
+
+#[test]
+fn unlimited_turns_take_the_short_way_round() {
+    let look_at = LookAt2D::default().with_max_turn_rate(degrees(90.0));
+    // 170 to -170 is 20 degrees anticlockwise, not 340 clockwise
+    assert_degrees(look_at.step(degrees(170.0), degrees(-170.0), 0.1), 179.0);
+    assert_degrees(look_at.step(degrees(170.0), degrees(-170.0), 1.0), -170.0);
+    assert_degrees(
+        LookAt2D::default().step(degrees(10.0), degrees(-100.0), 0.0),
+        -100.0,
+    );
+}
+
+#[test]
+fn limits_clamp_and_wrap_around_their_middle() {
+    // A turret facing left that can cover the back half of the circle
+    let look_at = LookAt2D::default()
+        .with_limits(degrees(90.0), degrees(270.0))
+        .with_max_turn_rate(degrees(10.0));
+    // Crossing 180 is inside the limits even though the angles wrap there
+    assert_degrees(look_at.step(degrees(170.0), degrees(-170.0), 1.0), 180.0);
+    // Straight ahead is out of reach, so it turns to the nearer limit
+    assert_degrees(look_at.step(degrees(100.0), degrees(10.0), 1.0), 90.0);
+
+    let look_at = LookAt2D::default()
+        .with_limits(degrees(-90.0), degrees(90.0))
+        .with_max_turn_rate(degrees(30.0));
+    // The short way from 80 to -170 crosses the back, so it has to go through 0 instead
+    assert_degrees(look_at.step(degrees(80.0), degrees(-170.0), 1.0), 50.0);
+    assert_degrees(look_at.step(degrees(-80.0), degrees(-170.0), 1.0), -90.0);
+}
+
+#[test]
+fn look_at_turns_to_face_entities_and_positions() {
+    let mut app = app();
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(5.0, 5.0),
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            ..default()
+        })
+        .id();
+    let target = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(5.0, -5.0),
+            ..default()
+        })
+        .id();
+    let relative = app
+        .world_mut()
+        .spawn((SpatialBundle2D::default(), LookAt2D::entity(target)))
+        .set_parent(parent)
+        .id();
+    let absolute = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                r_prop: RotationPropagation::Absolute,
+                ..default()
+            },
+            LookAt2D::entity(target),
+        ))
+        .set_parent(parent)
+        .id();
+    let offset = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D::default(),
+            LookAt2D::position(Position2D::new(-10.0, 0.0)).with_offset(Degrees::from_f32(-90.0)),
+        ))
+        .id();
+    app.update();
+
+    let rotation = |entity| app.world().get::<Rotation2D>(entity).unwrap().radians();
+    // The target is straight down in the world, a quarter turn left of the parent
+    assert_degrees(rotation(relative), -180.0);
+    assert_degrees(rotation(absolute), -90.0);
+    assert_degrees(rotation(offset), 90.0);
+
+    let (_, world, _) = app
+        .world()
+        .get::<GlobalTransform>(relative)
+        .unwrap()
+        .to_scale_rotation_translation();
+    assert_degrees(Radians::from_f32(world.to_euler(EulerRot::ZYX).0), -90.0);
+}
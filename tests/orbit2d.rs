@@ -0,0 +1,73 @@
+mod common;
+
+use bevy::prelude::*;
+use common::{app, position, world_position};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-3;
+
+#[test]
+fn offsets_and_tangents_trace_the_ellipse() {
+    let orbit = Orbit2D::position(Position2D::ZERO, 1.0)
+        .with_radii(4.0, 2.0)
+        .with_phase(Rotation2D::from_f32_degrees(90.0));
+    assert!(orbit.offset().abs_diff_eq(Vec2::new(0.0, 2.0), EPSILON));
+    assert!(orbit.tangent().abs_diff_eq(Vec2::new(-4.0, 0.0), EPSILON));
+
+    let backwards = orbit.with_speed(AngularVelocity2D::from_radians(-1.0));
+    assert!(backwards
+        .tangent()
+        .abs_diff_eq(Vec2::new(4.0, 0.0), EPSILON));
+}
+
+// Relative children get the orbit in their parent's space, absolute ones keep it in world space
+#[test]
+fn orbits_land_in_world_space_under_a_moved_parent() {
+    let mut app = app();
+    let center = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(10.0, 0.0),
+            ..default()
+        })
+        .id();
+    let parent = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(-30.0, 20.0),
+            rotation: Rotation2D::from_f32_degrees(45.0),
+            ..default()
+        })
+        .id();
+    let orbit = Orbit2D::entity(center, 5.0)
+        .with_phase(Rotation2D::from_f32_degrees(90.0))
+        .facing_tangent();
+    let relative = app
+        .world_mut()
+        .spawn((SpatialBundle2D::default(), orbit))
+        .set_parent(parent)
+        .id();
+    let absolute = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                p_prop: PositionPropagation::Absolute,
+                ..default()
+            },
+            orbit,
+        ))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    let expected = Vec2::new(10.0, 5.0);
+    assert!(world_position(&app, relative).abs_diff_eq(expected, EPSILON));
+    assert!(world_position(&app, absolute).abs_diff_eq(expected, EPSILON));
+    assert!(position(&app, absolute).abs_diff_eq(expected, EPSILON));
+
+    // Facing back along -x in the world, both still rotate relative to the parent
+    for entity in [relative, absolute] {
+        let rotation = app.world().get::<Rotation2D>(entity).unwrap();
+        assert!((rotation.degrees().to_f32() - 135.0).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use common::app;
+use rantz_spatial2d::prelude::*;
+
+fn spawn(app: &mut App, x: f32, y: f32, order: f32, bounds: Bounds2D) -> Entity {
+    app.world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(x, y),
+                draw_order: DrawOrder::new(order),
+                ..default()
+            },
+            bounds,
+        ))
+        .id()
+}
+
+fn pick_point(app: &mut App, x: f32, y: f32) -> Vec<Entity> {
+    app.world_mut()
+        .run_system_once(move |picking: Picking2D| picking.pick_point(Position2D::new(x, y)))
+}
+
+fn pick_rect(app: &mut App, rect: Rect) -> Vec<Entity> {
+    app.world_mut()
+        .run_system_once(move |picking: Picking2D| picking.pick_rect(rect))
+}
+
+#[test]
+fn the_highest_accumulated_draw_order_is_picked_first() {
+    let mut app = app();
+    let back = spawn(&mut app, 0.0, 0.0, 1.0, Bounds2D::rect(Vec2::splat(10.0)));
+    let front = spawn(&mut app, 2.0, 0.0, 3.0, Bounds2D::rect(Vec2::splat(10.0)));
+    // 1 + 2.5 beats the 3 of `front` once the parent order is added
+    let child = spawn(&mut app, 0.0, 0.0, 2.5, Bounds2D::circle(2.0));
+    app.world_mut().entity_mut(child).set_parent(back);
+    app.update();
+
+    assert_eq!(pick_point(&mut app, 1.0, 0.0), vec![child, front, back]);
+    assert_eq!(pick_point(&mut app, 6.0, 0.0), vec![front]);
+    assert!(pick_point(&mut app, 50.0, 0.0).is_empty());
+    let topmost = app
+        .world_mut()
+        .run_system_once(|picking: Picking2D| picking.topmost(Position2D::new(-4.0, 4.0)));
+    assert_eq!(topmost, Some(back));
+}
+
+#[test]
+fn rotated_and_scaled_shapes_are_picked_exactly() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                rotation: Rotation2D::from_f32_degrees(45.0),
+                scale: Scale2D::new(4.0, 1.0),
+                ..default()
+            },
+            Bounds2D::rect(Vec2::splat(2.0)),
+        ))
+        .id();
+    app.update();
+
+    // Along the long diagonal axis
+    assert_eq!(pick_point(&mut app, 2.5, 2.5), vec![entity]);
+    // Inside the aabb but off the rotated rect
+    assert!(pick_point(&mut app, 2.5, -2.5).is_empty());
+    assert_eq!(
+        pick_rect(&mut app, Rect::new(2.0, -3.0, 3.0, -2.0)),
+        Vec::<Entity>::new()
+    );
+    assert_eq!(
+        pick_rect(&mut app, Rect::new(2.0, 2.0, 10.0, 10.0)),
+        vec![entity]
+    );
+}
+
+#[test]
+fn filters_limit_the_candidates() {
+    let mut app = app();
+    let plain = spawn(&mut app, 0.0, 0.0, 0.0, Bounds2D::circle(5.0));
+    let layered = spawn(&mut app, 0.0, 0.0, 1.0, Bounds2D::circle(5.0));
+    app.world_mut()
+        .entity_mut(layered)
+        .insert(Layers2D::layer(2));
+    app.update();
+
+    let by = app.world_mut().run_system_once(move |picking: Picking2D| {
+        picking.pick_point_by(Position2D::new(0.0, 0.0), |entity| entity != layered)
+    });
+    assert_eq!(by, vec![plain]);
+    let filtered =
+        app.world_mut()
+            .run_system_once(|picking: Picking2D, layers: Query<&Layers2D>| {
+                picking.pick_rect_filtered(Rect::new(-1.0, -1.0, 1.0, 1.0), &layers)
+            });
+    assert_eq!(filtered, vec![layered]);
+    let topmost = app.world_mut().run_system_once(
+        |picking: Picking2D, plain: Query<(), Without<Layers2D>>| {
+            picking.topmost_filtered(Position2D::new(0.0, 0.0), &plain)
+        },
+    );
+    assert_eq!(topmost, Some(plain));
+}
+
+#[test]
+fn the_quadtree_agrees_with_a_full_scan() {
+    let mut indexed = app();
+    indexed.insert_resource(Quadtree2D::with_limits(
+        Rect::new(-100.0, -100.0, 100.0, 100.0),
+        6,
+        2,
+    ));
+    let mut plain = app();
+    for app in [&mut indexed, &mut plain] {
+        for index in 0..40 {
+            let x = (index % 8) as f32 * 25.0 - 90.0;
+            let y = (index / 8) as f32 * 40.0 - 90.0;
+            let bounds = if index % 3 == 0 {
+                Bounds2D::circle(15.0)
+            } else {
+                Bounds2D::rect(Vec2::new(30.0, 20.0))
+            };
+            spawn(app, x, y, (index % 5) as f32, bounds);
+        }
+        // A point with no bounds is indexed but never picked
+        app.world_mut().spawn(SpatialBundle2D::default());
+        app.update();
+    }
+    assert_eq!(indexed.world().resource::<Quadtree2D>().len(), 41);
+
+    let mut points = 0;
+    for x in (-110..=110).step_by(13) {
+        for y in (-110..=110).step_by(17) {
+            let (x, y) = (x as f32, y as f32);
+            let picked = pick_point(&mut indexed, x, y);
+            assert_eq!(picked, pick_point(&mut plain, x, y), "({x}, {y})");
+            points += picked.len();
+        }
+    }
+    assert!(points > 0);
+    for rect in [
+        Rect::new(-50.0, -50.0, 50.0, 50.0),
+        Rect::new(-95.0, 55.0, -60.0, 70.0),
+        Rect::from_center_size(Vec2::new(-65.0, -90.0), Vec2::ZERO),
+    ] {
+        let picked = pick_rect(&mut indexed, rect);
+        assert!(!picked.is_empty());
+        assert_eq!(picked, pick_rect(&mut plain, rect), "{rect:?}");
+    }
+}
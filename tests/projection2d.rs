@@ -0,0 +1,189 @@
+mod common;
+
+use bevy::{math::Affine2, prelude::*};
+use common::{app, world_translation};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+#[test]
+fn projections_round_trip() {
+    for projection in [
+        Projection2D::isometric(),
+        Projection2D::dimetric(),
+        Projection2D::FLAT,
+    ] {
+        let ground = Position2D::new(12.0, -7.0);
+        let screen = projection.project_elevated(ground, 3.0);
+        let back = projection.unproject_elevated(screen, 3.0);
+        assert!(Vec2::from(back).abs_diff_eq(ground.into(), EPSILON));
+    }
+    // Moving along both ground axes at once goes straight up the screen
+    let screen = Projection2D::dimetric().project(Position2D::new(10.0, 10.0));
+    assert!(screen.x.abs() < EPSILON);
+    assert!(screen.y > 0.0);
+}
+
+#[test]
+fn roots_are_projected_with_depth() {
+    let mut app = app();
+    let projection = Projection2D::dimetric();
+    app.insert_resource(projection);
+    let near = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(10.0, -30.0),
+            draw_order: DrawOrder::new(1.0),
+            ..default()
+        })
+        .id();
+    let far = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(10.0, 30.0),
+            draw_order: DrawOrder::new(1.0),
+            ..default()
+        })
+        .id();
+    app.update();
+
+    let ground = Position2D::new(10.0, -30.0);
+    let expected = projection
+        .project(ground)
+        .extend(1.0 + projection.depth(ground));
+    assert!(world_translation(&app, near).abs_diff_eq(expected, EPSILON));
+    assert!(world_translation(&app, near).z > world_translation(&app, far).z);
+}
+
+#[test]
+fn children_are_projected_from_their_world_ground_position() {
+    let mut app = app();
+    let projection = Projection2D::isometric();
+    app.insert_resource(projection);
+    let parent = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(20.0, 5.0),
+                rotation: Rotation2D::from_f32_degrees(90.0),
+                scale: Scale2D::new(2.0, 2.0),
+                ..default()
+            },
+            Elevation2D(4.0),
+        ))
+        .id();
+    let child = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(3.0, 1.0),
+                draw_order: DrawOrder::new(0.5),
+                ..default()
+            },
+            Elevation2D(1.0),
+        ))
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    let parent_ground = Affine2::from_scale_angle_translation(
+        Vec2::splat(2.0),
+        90f32.to_radians(),
+        Vec2::new(20.0, 5.0),
+    );
+    let ground = Position2D::from(parent_ground.transform_point2(Vec2::new(3.0, 1.0)));
+    let expected = projection
+        .project_elevated(ground, 5.0)
+        .extend(0.5 + projection.depth(ground));
+    assert!(
+        world_translation(&app, child).abs_diff_eq(expected, EPSILON),
+        "{:?} != {expected:?}",
+        world_translation(&app, child)
+    );
+    // Rotation and scale still compose as usual
+    let (scale, rotation, _) = app
+        .world()
+        .get::<GlobalTransform>(child)
+        .unwrap()
+        .to_scale_rotation_translation();
+    assert!(scale.truncate().abs_diff_eq(Vec2::splat(2.0), EPSILON));
+    assert!((rotation.to_euler(EulerRot::ZYX).0.to_degrees() - 90.0).abs() < 1e-3);
+}
+
+#[test]
+fn a_root_projection_overrides_the_resource() {
+    let mut app = app();
+    app.insert_resource(Projection2D::isometric());
+    let root = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(10.0, 0.0),
+                ..default()
+            },
+            Projection2D::FLAT,
+        ))
+        .id();
+    let child = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(0.0, 10.0),
+            ..default()
+        })
+        .set_parent(root)
+        .id();
+    app.update();
+
+    assert!(world_translation(&app, child).abs_diff_eq(Vec3::new(10.0, 10.0, 0.0), EPSILON));
+}
+
+#[test]
+fn snapping_happens_on_the_ground_plane() {
+    let mut app = app();
+    let projection = Projection2D::isometric();
+    app.insert_resource(projection)
+        .insert_resource(Grid2D::square(10.0));
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(13.0, 22.0),
+                ..default()
+            },
+            SnapToGrid::default(),
+        ))
+        .id();
+    app.update();
+
+    let expected = projection.project(Position2D::new(15.0, 25.0));
+    assert!(world_translation(&app, entity)
+        .truncate()
+        .abs_diff_eq(expected, EPSILON));
+}
+
+#[test]
+fn elevation_needs_a_projection() {
+    // Flat, even with a depth scale, is the same as none
+    for projection in [None, Some(Projection2D::FLAT.with_depth_scale(0.5))] {
+        let mut app = app();
+        if let Some(projection) = projection {
+            app.insert_resource(projection);
+        }
+        let entity = app
+            .world_mut()
+            .spawn((
+                SpatialBundle2D {
+                    position: Position2D::new(3.0, 4.0),
+                    ..default()
+                },
+                Elevation2D(10.0),
+            ))
+            .id();
+        app.update();
+
+        assert!(
+            world_translation(&app, entity).abs_diff_eq(Vec3::new(3.0, 4.0, 0.0), EPSILON),
+            "{projection:?}"
+        );
+    }
+}
@@ -0,0 +1,282 @@
+mod common;
+
+use bevy::{math::Affine2, prelude::*};
+use common::app;
+use proptest::{prelude::*, sample::Index};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+// Relative to the size of the numbers involved, deep chains multiply float error
+const TOLERANCE: f32 = 1e-4;
+
+fn world_affine(app: &App, entity: Entity) -> Affine2 {
+    let affine = app.world().get::<GlobalTransform>(entity).unwrap().affine();
+    Affine2::from_mat2_translation(
+        Mat2::from_cols(
+            affine.matrix3.x_axis.truncate(),
+            affine.matrix3.y_axis.truncate(),
+        ),
+        affine.translation.truncate(),
+    )
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    parent: Option<usize>,
+    position: Vec2,
+    degrees: f32,
+    scale: Vec2,
+    absolute_rotation: bool,
+    absolute_position: bool,
+    absolute_scale: bool,
+}
+
+fn magnitude() -> impl Strategy<Value = f32> {
+    (0.5f32..2.0, any::<bool>()).prop_map(|(size, flip)| if flip { -size } else { size })
+}
+
+// Parents always come earlier in the list, so every list is a forest
+fn nodes(
+    scale: impl Strategy<Value = Vec2>,
+    modes: impl Strategy<Value = (bool, bool, bool)>,
+) -> impl Strategy<Value = Vec<Node>> {
+    prop::collection::vec(
+        (
+            prop::option::weighted(0.8, any::<Index>()),
+            (-100.0f32..100.0, -100.0f32..100.0),
+            -720.0f32..720.0,
+            scale,
+            modes,
+        ),
+        1..10,
+    )
+    .prop_map(|nodes| {
+        nodes
+            .into_iter()
+            .enumerate()
+            .map(|(i, (parent, (x, y), degrees, scale, modes))| Node {
+                parent: parent.filter(|_| i > 0).map(|parent| parent.index(i)),
+                position: Vec2::new(x, y),
+                degrees,
+                scale,
+                absolute_rotation: modes.0,
+                absolute_position: modes.1,
+                absolute_scale: modes.2,
+            })
+            .collect()
+    })
+}
+
+// Any scale at all, only positions may be absolute since that never needs shear
+fn relative_nodes() -> impl Strategy<Value = Vec<Node>> {
+    nodes(
+        (magnitude(), magnitude()).prop_map(|(x, y)| Vec2::new(x, y)),
+        any::<bool>().prop_map(|position| (false, position, false)),
+    )
+}
+
+// Same size on both axes so world transforms never shear and every mode is exact
+fn mixed_nodes() -> impl Strategy<Value = Vec<Node>> {
+    nodes(
+        (magnitude(), any::<bool>()).prop_map(|(x, flip)| Vec2::new(x, if flip { -x } else { x })),
+        any::<(bool, bool, bool)>(),
+    )
+}
+
+fn spawn(app: &mut App, nodes: &[Node]) -> Vec<Entity> {
+    let mut entities: Vec<Entity> = Vec::new();
+    for node in nodes {
+        let prop = |absolute| if absolute { 1 } else { 0 };
+        let entity = app
+            .world_mut()
+            .spawn(SpatialBundle2D {
+                position: Position2D::new(node.position.x, node.position.y),
+                rotation: Rotation2D::from_f32_degrees(node.degrees),
+                scale: Scale2D::new(node.scale.x, node.scale.y),
+                r_prop: [RotationPropagation::Relative, RotationPropagation::Absolute]
+                    [prop(node.absolute_rotation)],
+                p_prop: [PositionPropagation::Relative, PositionPropagation::Absolute]
+                    [prop(node.absolute_position)],
+                s_prop: [ScalePropagation::Relative, ScalePropagation::Absolute]
+                    [prop(node.absolute_scale)],
+                ..default()
+            })
+            .id();
+        if let Some(parent) = node.parent {
+            app.world_mut()
+                .entity_mut(entity)
+                .set_parent(entities[parent]);
+        }
+        entities.push(entity);
+    }
+    app.update();
+    entities
+}
+
+fn local_affine(node: &Node) -> Affine2 {
+    Affine2::from_scale_angle_translation(node.scale, node.degrees.to_radians(), node.position)
+}
+
+fn close(a: Affine2, b: Affine2) -> bool {
+    let size = a
+        .to_cols_array()
+        .iter()
+        .fold(1.0f32, |size, value| size.max(value.abs()));
+    a.abs_diff_eq(b, TOLERANCE * size)
+}
+
+#[test]
+fn absolute_children_ignore_every_ancestor() {
+    let mut app = app();
+    let world = app.world_mut();
+    let grandparent = world
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(100.0, 50.0),
+            rotation: Rotation2D::from_f32_degrees(90.0),
+            scale: Scale2D::new(2.0, 2.0),
+            ..default()
+        })
+        .id();
+    let parent = world
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(10.0, 0.0),
+            rotation: Rotation2D::from_f32_degrees(-45.0),
+            ..default()
+        })
+        .set_parent(grandparent)
+        .id();
+    let child = world
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(-20.0, 10.0),
+            rotation: Rotation2D::from_f32_degrees(30.0),
+            scale: Scale2D::new(1.5, 1.5),
+            r_prop: RotationPropagation::Absolute,
+            p_prop: PositionPropagation::Absolute,
+            s_prop: ScalePropagation::Absolute,
+            ..default()
+        })
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    let expected = Affine2::from_scale_angle_translation(
+        Vec2::splat(1.5),
+        30f32.to_radians(),
+        Vec2::new(-20.0, 10.0),
+    );
+    let actual = world_affine(&app, child);
+    assert!(actual.abs_diff_eq(expected, EPSILON), "{actual:?}");
+}
+
+#[test]
+fn children_of_a_zero_scale_parent_stay_finite() {
+    let mut app = app();
+    let world = app.world_mut();
+    let parent = world
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(5.0, 5.0),
+            scale: Scale2D { x: 0.0, y: 1.0 },
+            ..default()
+        })
+        .id();
+    let child = world
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(3.0, 4.0),
+            p_prop: PositionPropagation::Absolute,
+            r_prop: RotationPropagation::Absolute,
+            ..default()
+        })
+        .set_parent(parent)
+        .id();
+    app.update();
+
+    assert!(world_affine(&app, child).is_finite());
+}
+
+proptest! {
+    #[test]
+    fn relative_children_match_analytic_composition(nodes in relative_nodes()) {
+        let mut app = app();
+        let entities = spawn(&mut app, &nodes);
+        let mut expected: Vec<Affine2> = Vec::new();
+        for (node, entity) in nodes.iter().zip(&entities) {
+            let parent = node.parent.map_or(Affine2::IDENTITY, |parent| expected[parent]);
+            let mut world = parent * local_affine(node);
+            if node.absolute_position {
+                world.translation = node.position;
+            }
+            let actual = world_affine(&app, *entity);
+            prop_assert!(close(actual, world), "{actual:?} != {world:?}");
+            expected.push(world);
+        }
+    }
+
+    #[test]
+    fn absolute_children_keep_their_world_values(nodes in mixed_nodes()) {
+        let mut app = app();
+        let entities = spawn(&mut app, &nodes);
+        for (node, entity) in nodes.iter().zip(&entities) {
+            let parent = node
+                .parent
+                .map_or(Affine2::IDENTITY, |parent| world_affine(&app, entities[parent]));
+            let parent_scale = Vec2::new(
+                parent.matrix2.x_axis.length(),
+                parent.matrix2.determinant() / parent.matrix2.x_axis.length(),
+            );
+            let turn = parent.matrix2.determinant().signum();
+            let angle = if node.absolute_rotation {
+                node.degrees.to_radians()
+            } else {
+                parent.matrix2.x_axis.to_angle() + turn * node.degrees.to_radians()
+            };
+            let scale = if node.absolute_scale {
+                node.scale
+            } else {
+                parent_scale * node.scale
+            };
+            let translation = if node.absolute_position {
+                node.position
+            } else {
+                parent.transform_point2(node.position)
+            };
+            let expected = Affine2::from_scale_angle_translation(scale, angle, translation);
+            let actual = world_affine(&app, *entity);
+            prop_assert!(close(actual, expected), "{node:?}: {actual:?} != {expected:?}");
+            if !node.absolute_rotation && !node.absolute_scale && !node.absolute_position {
+                let composed = parent * local_affine(node);
+                prop_assert!(close(actual, composed), "{actual:?} != {composed:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn world_rotations_round_trip_through_quat(nodes in mixed_nodes()) {
+        let mut app = app();
+        for entity in spawn(&mut app, &nodes) {
+            let (_, quat, _) = app
+                .world()
+                .get::<GlobalTransform>(entity)
+                .unwrap()
+                .to_scale_rotation_translation();
+            let rotation = Rotation2D::from(quat);
+            let back = Quat::from(rotation);
+            // q and -q are the same rotation
+            prop_assert!(
+                quat.abs_diff_eq(back, TOLERANCE) || quat.abs_diff_eq(-back, TOLERANCE),
+                "{quat:?} -> {rotation:?} -> {back:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rotations_round_trip_through_quat(degrees in -1080.0f32..1080.0) {
+        let rotation = Rotation2D::from_f32_degrees(degrees);
+        let back = Rotation2D::from(Quat::from(rotation));
+        let difference = (back.degrees().to_f32() - degrees).rem_euclid(360.0);
+        prop_assert!(
+            difference.min(360.0 - difference) < 1e-2,
+            "{degrees} came back as {}",
+            back.degrees().to_f32()
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use rantz_spatial2d::prelude::*;
+
+fn entity(index: u32) -> Entity {
+    Entity::from_raw(index)
+}
+
+fn sorted(mut entities: Vec<Entity>) -> Vec<Entity> {
+    entities.sort();
+    entities
+}
+
+fn tree() -> Quadtree2D {
+    Quadtree2D::with_limits(Rect::new(-100.0, -100.0, 100.0, 100.0), 6, 2)
+}
+
+#[test]
+fn points_inside_a_rect_query_are_found() {
+    let mut tree = tree();
+    tree.insert_point(entity(0), Position2D::new(10.0, 10.0));
+    tree.insert_point(entity(1), Position2D::new(-50.0, 20.0));
+
+    assert_eq!(
+        tree.within_rect(Rect::new(0.0, 0.0, 20.0, 20.0)),
+        vec![entity(0)]
+    );
+    // On the edge still counts
+    assert_eq!(
+        tree.within_rect(Rect::new(-50.0, 0.0, -40.0, 20.0)),
+        vec![entity(1)]
+    );
+}
+
+#[test]
+fn point_queries_find_rect_entries() {
+    let mut tree = tree();
+    tree.insert(entity(0), Rect::new(-10.0, -10.0, 10.0, 10.0));
+    tree.insert(entity(1), Rect::new(50.0, 50.0, 60.0, 60.0));
+
+    let point = Rect::from_center_size(Vec2::new(5.0, -5.0), Vec2::ZERO);
+    assert_eq!(tree.within_rect(point), vec![entity(0)]);
+    assert_eq!(
+        tree.within_radius(Position2D::new(55.0, 55.0), 0.0),
+        vec![entity(1)]
+    );
+}
+
+#[test]
+fn split_nodes_keep_every_entry_reachable() {
+    let mut tree = tree();
+    let mut points = Vec::new();
+    for index in 0..64 {
+        let point = Vec2::new(
+            (index % 8) as f32 * 20.0 - 75.0,
+            (index / 8) as f32 * 20.0 - 75.0,
+        );
+        tree.insert_point(entity(index), point.into());
+        points.push(point);
+    }
+    // Outside the root bounds
+    tree.insert_point(entity(100), Position2D::new(500.0, 0.0));
+
+    assert_eq!(tree.len(), 65);
+    assert_eq!(
+        tree.within_rect(Rect::new(-200.0, -200.0, 600.0, 200.0))
+            .len(),
+        65
+    );
+    let query = Rect::new(-60.0, -60.0, 0.0, 0.0);
+    let expected: Vec<Entity> = points
+        .iter()
+        .enumerate()
+        .filter(|(_, point)| query.contains(**point))
+        .map(|(index, _)| entity(index as u32))
+        .collect();
+    assert_eq!(sorted(tree.within_rect(query)), expected);
+    assert_eq!(
+        tree.within_radius(Position2D::new(500.0, 0.0), 1.0),
+        vec![entity(100)]
+    );
+}
+
+#[test]
+fn moving_and_removing_entries() {
+    let mut tree = tree();
+    tree.insert_point(entity(0), Position2D::new(10.0, 10.0));
+    tree.insert_point(entity(0), Position2D::new(-80.0, -80.0));
+
+    assert_eq!(tree.len(), 1);
+    assert!(tree
+        .within_radius(Position2D::new(10.0, 10.0), 1.0)
+        .is_empty());
+    assert_eq!(
+        tree.entity_bounds(entity(0)).map(|rect| rect.center()),
+        Some(Vec2::new(-80.0, -80.0))
+    );
+
+    assert!(tree.remove(entity(0)));
+    assert!(!tree.contains(entity(0)));
+    assert!(tree
+        .within_radius(Position2D::new(-80.0, -80.0), 1.0)
+        .is_empty());
+}
+
+#[test]
+fn k_nearest_measures_to_the_closest_edge() {
+    let mut tree = tree();
+    tree.insert_point(entity(0), Position2D::new(30.0, 0.0));
+    tree.insert(entity(1), Rect::new(5.0, -50.0, 10.0, 50.0));
+    tree.insert_point(entity(2), Position2D::new(-20.0, 0.0));
+
+    let center = Position2D::new(0.0, 0.0);
+    assert_eq!(
+        tree.k_nearest(center, 3),
+        vec![entity(1), entity(2), entity(0)]
+    );
+    assert_eq!(
+        tree.k_nearest_by(center, 1, |e| e != entity(1)),
+        vec![entity(2)]
+    );
+}
+
+#[test]
+fn emptied_nodes_merge_back_into_their_parent() {
+    let cluster = |round: u32| {
+        let center = Vec2::from_angle(round as f32) * 70.0;
+        (0..16).map(move |index| {
+            let offset = Vec2::new((index % 4) as f32, (index / 4) as f32);
+            (entity(index), Position2D::from(center + offset))
+        })
+    };
+
+    // A tight cluster forces splits down to the depth limit wherever it goes
+    let mut tree = tree();
+    for round in 0..40 {
+        let mut fresh = self::tree();
+        for (entity, position) in cluster(round) {
+            tree.insert_point(entity, position);
+            fresh.insert_point(entity, position);
+        }
+        assert!(tree.node_count() <= fresh.node_count());
+        let center = cluster(round).next().unwrap().1;
+        assert_eq!(tree.within_radius(center, 5.0).len(), 16);
+    }
+
+    for index in 0..16 {
+        tree.remove(entity(index));
+    }
+    assert_eq!(tree.node_count(), 1);
+}
@@ -0,0 +1,299 @@
+mod common;
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use common::app;
+use rantz_spatial2d::prelude::*;
+use std::f32::consts::SQRT_2;
+
+const EPSILON: f32 = 1e-3;
+
+fn spawn(app: &mut App, x: f32, y: f32, bounds: Bounds2D) -> Entity {
+    app.world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(x, y),
+                ..default()
+            },
+            bounds,
+        ))
+        .id()
+}
+
+fn all_hits(app: &mut App, origin: Vec2, degrees: f32, max_distance: f32) -> Vec<RayHit2D> {
+    app.world_mut().run_system_once(move |raycast: Raycast2D| {
+        raycast.all_hits(
+            origin.into(),
+            Rotation2D::from_f32_degrees(degrees),
+            max_distance,
+        )
+    })
+}
+
+fn assert_hit(hit: &RayHit2D, entity: Entity, distance: f32, point: Vec2, normal: Vec2) {
+    assert_eq!(hit.entity, entity);
+    assert!((hit.distance - distance).abs() < EPSILON, "{hit:?}");
+    assert!(Vec2::from(hit.point).abs_diff_eq(point, EPSILON), "{hit:?}");
+    assert!(hit.normal.abs_diff_eq(normal, EPSILON), "{hit:?}");
+}
+
+#[test]
+fn hits_are_sorted_by_distance_with_their_surface() {
+    let mut app = app();
+    // Spawned furthest first so the order has to come from the distance
+    let circle = spawn(&mut app, 10.0, 1.5, Bounds2D::circle(3.0));
+    let rect = spawn(&mut app, 0.0, 0.0, Bounds2D::rect(Vec2::splat(4.0)));
+    app.update();
+
+    let hits = all_hits(&mut app, Vec2::new(-20.0, 0.0), 0.0, 100.0);
+    assert_eq!(hits.len(), 2);
+    assert_hit(
+        &hits[0],
+        rect,
+        18.0,
+        Vec2::new(-2.0, 0.0),
+        Vec2::new(-1.0, 0.0),
+    );
+    // Off the circle's centre line, so the normal tilts away from it
+    let half_chord = (9.0f32 - 2.25).sqrt();
+    assert_hit(
+        &hits[1],
+        circle,
+        30.0 - half_chord,
+        Vec2::new(10.0 - half_chord, 0.0),
+        Vec2::new(-half_chord, -1.5) / 3.0,
+    );
+
+    let first = app.world_mut().run_system_once(|raycast: Raycast2D| {
+        raycast.first_hit(
+            Position2D::new(-20.0, 0.0),
+            Rotation2D::from_f32_degrees(0.0),
+            100.0,
+        )
+    });
+    assert_eq!(first, Some(hits[0]));
+    assert!(all_hits(&mut app, Vec2::new(-20.0, 0.0), 180.0, 100.0).is_empty());
+}
+
+#[test]
+fn rotated_and_scaled_bounds_are_hit_exactly() {
+    let mut app = app();
+    // 2 by 8 once scaled and stood on end
+    let tall = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                rotation: Rotation2D::from_f32_degrees(90.0),
+                scale: Scale2D::new(4.0, 1.0),
+                ..default()
+            },
+            Bounds2D::rect(Vec2::splat(2.0)),
+        ))
+        .id();
+    let diamond = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(0.0, -20.0),
+                rotation: Rotation2D::from_f32_degrees(45.0),
+                ..default()
+            },
+            Bounds2D::rect(Vec2::splat(2.0)),
+        ))
+        .id();
+    app.update();
+
+    let hits = all_hits(&mut app, Vec2::new(-20.0, 3.0), 0.0, 100.0);
+    assert_eq!(hits.len(), 1);
+    assert_hit(
+        &hits[0],
+        tall,
+        19.0,
+        Vec2::new(-1.0, 3.0),
+        Vec2::new(-1.0, 0.0),
+    );
+
+    let x = -(SQRT_2 - 0.5);
+    let hits = all_hits(&mut app, Vec2::new(-20.0, -20.5), 0.0, 100.0);
+    assert_eq!(hits.len(), 1);
+    assert_hit(
+        &hits[0],
+        diamond,
+        20.0 + x,
+        Vec2::new(x, -20.5),
+        Vec2::new(-1.0, -1.0) / SQRT_2,
+    );
+    // Parallel to an edge, through the diamond's aabb but outside the diamond
+    assert!(all_hits(&mut app, Vec2::new(-10.0, -7.5), -45.0, 100.0).is_empty());
+}
+
+#[test]
+fn max_distance_and_layers_limit_the_hits() {
+    let mut app = app();
+    let near = spawn(&mut app, 0.0, 0.0, Bounds2D::circle(1.0));
+    let far = spawn(&mut app, 10.0, 0.0, Bounds2D::circle(1.0));
+    app.world_mut().entity_mut(far).insert(Layers2D::layer(3));
+    app.update();
+
+    assert!(all_hits(&mut app, Vec2::new(-10.0, 0.0), 0.0, 8.5).is_empty());
+    let hits = all_hits(&mut app, Vec2::new(-10.0, 0.0), 0.0, 9.5);
+    assert_eq!(
+        hits.iter().map(|hit| hit.entity).collect::<Vec<_>>(),
+        [near]
+    );
+
+    let in_layers = |app: &mut App, layers: Layers2D| {
+        app.world_mut().run_system_once(move |raycast: Raycast2D| {
+            raycast
+                .all_hits_in(
+                    Position2D::new(-10.0, 0.0),
+                    Rotation2D::from_f32_degrees(0.0),
+                    100.0,
+                    layers,
+                )
+                .into_iter()
+                .map(|hit| hit.entity)
+                .collect::<Vec<_>>()
+        })
+    };
+    // No Layers2D is the default layer
+    assert_eq!(in_layers(&mut app, Layers2D::DEFAULT), [near]);
+    assert_eq!(in_layers(&mut app, Layers2D::layer(3)), [far]);
+    assert_eq!(in_layers(&mut app, Layers2D::ALL), [near, far]);
+    let first = app.world_mut().run_system_once(|raycast: Raycast2D| {
+        raycast.first_hit_in(
+            Position2D::new(-10.0, 0.0),
+            Rotation2D::from_f32_degrees(0.0),
+            100.0,
+            Layers2D::NONE,
+        )
+    });
+    assert_eq!(first, None);
+}
+
+#[test]
+fn line_of_sight_ignores_what_it_is_told_to() {
+    let mut app = app();
+    let wall = spawn(&mut app, 0.0, 0.0, Bounds2D::rect(Vec2::new(2.0, 10.0)));
+    app.update();
+
+    let sight = move |app: &mut App, to: Vec2, layers: Layers2D, ignore: Vec<Entity>| {
+        app.world_mut().run_system_once(move |raycast: Raycast2D| {
+            raycast.line_of_sight(Position2D::new(-10.0, 0.0), to.into(), layers, &ignore)
+        })
+    };
+    assert!(!sight(
+        &mut app,
+        Vec2::new(10.0, 0.0),
+        Layers2D::ALL,
+        vec![]
+    ));
+    assert!(sight(
+        &mut app,
+        Vec2::new(10.0, 0.0),
+        Layers2D::ALL,
+        vec![wall]
+    ));
+    assert!(sight(
+        &mut app,
+        Vec2::new(10.0, 0.0),
+        Layers2D::layer(5),
+        vec![]
+    ));
+    // Stops short of the wall
+    assert!(sight(&mut app, Vec2::new(-5.0, 0.0), Layers2D::ALL, vec![]));
+    assert!(sight(
+        &mut app,
+        Vec2::new(10.0, 20.0),
+        Layers2D::ALL,
+        vec![]
+    ));
+}
+
+#[test]
+fn rays_starting_inside_hit_at_their_origin() {
+    let mut app = app();
+    let rect = spawn(&mut app, 0.0, 0.0, Bounds2D::rect(Vec2::splat(10.0)));
+    let circle = spawn(&mut app, 20.0, 0.0, Bounds2D::circle(5.0));
+    app.update();
+
+    let hits = all_hits(&mut app, Vec2::new(1.0, 2.0), 90.0, 100.0);
+    assert_eq!(hits.len(), 1);
+    assert_hit(
+        &hits[0],
+        rect,
+        0.0,
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, -1.0),
+    );
+    let hits = all_hits(&mut app, Vec2::new(20.0, 1.0), 180.0, 100.0);
+    assert_eq!(
+        hits.iter().map(|hit| hit.entity).collect::<Vec<_>>(),
+        [circle, rect]
+    );
+    assert_hit(
+        &hits[0],
+        circle,
+        0.0,
+        Vec2::new(20.0, 1.0),
+        Vec2::new(1.0, 0.0),
+    );
+    assert_hit(
+        &hits[1],
+        rect,
+        15.0,
+        Vec2::new(5.0, 1.0),
+        Vec2::new(1.0, 0.0),
+    );
+}
+
+#[test]
+fn the_quadtree_gives_the_same_hits_as_a_full_scan() {
+    let mut indexed = app();
+    indexed.insert_resource(Quadtree2D::with_limits(
+        Rect::new(-100.0, -100.0, 100.0, 100.0),
+        6,
+        2,
+    ));
+    let mut plain = app();
+    for app in [&mut indexed, &mut plain] {
+        for index in 0..40 {
+            let x = (index % 8) as f32 * 25.0 - 90.0;
+            let y = (index / 8) as f32 * 40.0 - 90.0;
+            let bounds = if index % 3 == 0 {
+                Bounds2D::circle(6.0)
+            } else {
+                Bounds2D::rect(Vec2::new(12.0, 8.0))
+            };
+            let entity = spawn(app, x, y, bounds);
+            if index % 4 == 0 {
+                app.world_mut()
+                    .entity_mut(entity)
+                    .insert(Layers2D::layer(1));
+            }
+        }
+        // Bounds on something the index never holds can't be hit either way
+        app.world_mut()
+            .spawn((SpatialBundle::default(), Bounds2D::circle(500.0)));
+        app.update();
+    }
+
+    let mut total = 0;
+    for origin in [
+        Vec2::new(-95.0, -95.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(80.0, -30.0),
+        Vec2::new(-100.0, 12.0),
+    ] {
+        for degrees in (0..360).step_by(15) {
+            let degrees = degrees as f32;
+            let hits = all_hits(&mut indexed, origin, degrees, 150.0);
+            assert_eq!(
+                hits,
+                all_hits(&mut plain, origin, degrees, 150.0),
+                "{origin} at {degrees}"
+            );
+            total += hits.len();
+        }
+    }
+    assert!(total > 0);
+}
@@ -0,0 +1,225 @@
+mod common;
+
+use bevy::{ecs::entity::EntityHashMap, prelude::*, scene::serde::SceneDeserializer};
+use common::{app, named};
+use rantz_spatial2d::prelude::*;
+use serde::de::DeserializeSeed;
+
+const EPSILON: f32 = 1e-4;
+
+// Derived state is left out on purpose, loading has to rebuild it from the 2D components
+fn save(world: &World) -> String {
+    let entities = world
+        .iter_entities()
+        .filter(|entity| entity.contains::<Position2D>())
+        .map(|entity| entity.id());
+    let scene = DynamicSceneBuilder::from_world(world)
+        .deny::<Transform>()
+        .deny::<GlobalTransform>()
+        .extract_entities(entities)
+        .build();
+    let registry = world.resource::<AppTypeRegistry>().read();
+    scene.serialize(&registry).unwrap()
+}
+
+fn load(app: &mut App, text: &str) {
+    let scene = {
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let mut deserializer = ron::de::Deserializer::from_str(text).unwrap();
+        SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap()
+    };
+    scene
+        .write_to_world(app.world_mut(), &mut EntityHashMap::default())
+        .unwrap();
+}
+
+fn global(app: &mut App, name: &str) -> GlobalTransform {
+    let entity = named(app, name);
+    *app.world().get::<GlobalTransform>(entity).unwrap()
+}
+
+fn assert_close(a: GlobalTransform, b: GlobalTransform) {
+    let (a, b) = (a.compute_transform(), b.compute_transform());
+    assert!(
+        a.translation.abs_diff_eq(b.translation, EPSILON),
+        "{a:?} != {b:?}"
+    );
+    assert!(
+        a.rotation.abs_diff_eq(b.rotation, EPSILON),
+        "{a:?} != {b:?}"
+    );
+    assert!(a.scale.abs_diff_eq(b.scale, EPSILON), "{a:?} != {b:?}");
+}
+
+fn spawn_hierarchy(world: &mut World) {
+    let root = world
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(10.0, -4.0),
+                rotation: Rotation2D::from_f32_degrees(90.0),
+                scale: Scale2D::new(2.0, 2.0),
+                draw_order: DrawOrder::new(1.0),
+                ..default()
+            },
+            Name::new("root"),
+        ))
+        .id();
+    let absolute = world
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(5.0, 0.0),
+                rotation: Rotation2D::from_f32_degrees(30.0),
+                scale: Scale2D::new(0.5, 1.5),
+                r_prop: RotationPropagation::Absolute,
+                s_prop: ScalePropagation::Absolute,
+                ..default()
+            },
+            Name::new("absolute"),
+        ))
+        .set_parent(root)
+        .id();
+    world
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(-3.0, 7.0),
+                rotation: Rotation2D::from_f32_degrees(-45.0),
+                draw_order: DrawOrder::new(2.0),
+                p_prop: PositionPropagation::Absolute,
+                ..default()
+            },
+            Name::new("absolute_position"),
+        ))
+        .set_parent(absolute);
+    world
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(1.0, 1.0),
+                rotation: Rotation2D::from_f32_degrees(180.0),
+                ..default()
+            },
+            Name::new("relative"),
+        ))
+        .set_parent(absolute);
+}
+
+const NAMES: [&str; 4] = ["root", "absolute", "absolute_position", "relative"];
+
+#[test]
+fn nested_propagation_survives_save_and_reload() {
+    let mut original = app();
+    spawn_hierarchy(original.world_mut());
+    original.update();
+    let text = save(original.world());
+
+    let mut loaded = app();
+    load(&mut loaded, &text);
+    loaded.update();
+
+    for name in NAMES {
+        assert_close(global(&mut original, name), global(&mut loaded, name));
+    }
+    let absolute = named(&mut loaded, "absolute");
+    assert_eq!(
+        loaded.world().get::<RotationPropagation>(absolute),
+        Some(&RotationPropagation::Absolute)
+    );
+}
+
+#[test]
+fn reloading_twice_is_stable() {
+    let mut original = app();
+    spawn_hierarchy(original.world_mut());
+    original.update();
+    let first = save(original.world());
+
+    let mut loaded = app();
+    load(&mut loaded, &first);
+    loaded.update();
+    let second = save(loaded.world());
+
+    let mut reloaded = app();
+    load(&mut reloaded, &second);
+    reloaded.update();
+    for name in NAMES {
+        assert_close(global(&mut original, name), global(&mut reloaded, name));
+    }
+}
+
+#[test]
+fn missing_components_are_filled_in_on_load() {
+    let mut original = app();
+    original
+        .world_mut()
+        .spawn((Position2D::new(3.0, 4.0), Name::new("bare")));
+    let text = save(original.world());
+
+    let mut loaded = app();
+    load(&mut loaded, &text);
+    loaded.update();
+
+    let bare = named(&mut loaded, "bare");
+    let world = loaded.world();
+    assert_eq!(world.get::<Rotation2D>(bare), Some(&Rotation2D::default()));
+    assert_eq!(world.get::<Scale2D>(bare), Some(&Scale2D::default()));
+    assert_eq!(
+        world
+            .get::<Transform>(bare)
+            .map(|transform| transform.translation),
+        Some(Vec3::new(3.0, 4.0, 0.0))
+    );
+}
+
+#[test]
+fn entity_targets_are_remapped() {
+    let mut original = app();
+    let world = original.world_mut();
+    let target = world
+        .spawn((
+            SpatialBundle2D {
+                position: Position2D::new(0.0, 10.0),
+                ..default()
+            },
+            Name::new("target"),
+        ))
+        .id();
+    world.spawn((
+        SpatialBundle2D::default(),
+        LookAt2D::entity(target),
+        Name::new("watcher"),
+    ));
+    original.update();
+    let text = save(original.world());
+
+    // Something already occupying the old ids makes a missing remap show up
+    let mut loaded = app();
+    for _ in 0..4 {
+        loaded.world_mut().spawn_empty();
+    }
+    load(&mut loaded, &text);
+    loaded.update();
+
+    let target = named(&mut loaded, "target");
+    let watcher = named(&mut loaded, "watcher");
+    assert_eq!(
+        loaded
+            .world()
+            .get::<LookAt2D>(watcher)
+            .map(|look| look.target),
+        Some(LookTarget2D::Entity(target))
+    );
+    let rotation = loaded.world().get::<Rotation2D>(watcher).unwrap();
+    assert!((rotation.degrees().to_f32() - 90.0).abs() < EPSILON);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn rotations_are_saved_in_degrees() {
+    let mut original = app();
+    spawn_hierarchy(original.world_mut());
+    let text = save(original.world());
+    assert!(text.contains("Rotation2D\": 90.0"), "{text}");
+}
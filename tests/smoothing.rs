@@ -0,0 +1,147 @@
+mod common;
+
+use bevy::prelude::*;
+use common::manual_time;
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-3;
+const DELTA: f32 = 0.015625;
+
+fn app() -> App {
+    let mut app = common::app();
+    manual_time(&mut app, DELTA);
+    app
+}
+
+#[test]
+fn exp_decay_closes_the_gap_at_the_same_rate_whatever_the_step() {
+    assert!((exp_decay(0.0, 10.0, 2.0, 0.5) - 10.0 * (1.0 - (-1.0f32).exp())).abs() < EPSILON);
+
+    let mut stepped = Vec2::new(-4.0, 8.0);
+    for _ in 0..64 {
+        stepped = exp_decay(stepped, Vec2::ZERO, 3.0, DELTA);
+    }
+    let once = exp_decay(Vec2::new(-4.0, 8.0), Vec2::ZERO, 3.0, 1.0);
+    assert!(stepped.abs_diff_eq(once, EPSILON));
+    assert_eq!(exp_decay(5.0, 5.0, 3.0, 1.0), 5.0);
+}
+
+#[test]
+fn exp_decay_angles_take_the_short_way_round() {
+    let current = Radians::from_f32(170f32.to_radians());
+    let target = Radians::from_f32((-170f32).to_radians());
+    let next = exp_decay_angle(current, target, 100.0, 1.0);
+    // Unwrapped past 180 rather than swinging back through 0
+    assert!((next.to_f32() - 190f32.to_radians()).abs() < EPSILON);
+
+    let half = exp_decay_degrees(
+        Degrees::from_f32(-170.0),
+        Degrees::from_f32(170.0),
+        2f32.ln(),
+        1.0,
+    );
+    assert!((half.to_f32() + 180.0).abs() < EPSILON);
+}
+
+#[test]
+fn smooth_damp_settles_without_overshooting() {
+    let mut current = 0.0;
+    let mut velocity = 0.0;
+    let mut steps = Vec::new();
+    for _ in 0..128 {
+        current = smooth_damp(current, 10.0, &mut velocity, 0.3, DELTA);
+        steps.push(current);
+    }
+    assert!(steps.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(steps.iter().all(|step| *step <= 10.0));
+    assert!((current - 10.0).abs() < 0.01);
+    assert!(velocity.abs() < 0.1);
+
+    // A critically damped spring from rest is at 1 - 3 e^-2 of the way after smooth_time
+    let mut current = Vec2::ZERO;
+    let mut velocity = Vec2::ZERO;
+    let target = Vec2::new(10.0, -10.0);
+    for _ in 0..64 {
+        current = smooth_damp(current, target, &mut velocity, 1.0, DELTA);
+    }
+    let expected = target * (1.0 - 3.0 * (-2.0f32).exp());
+    assert!(current.abs_diff_eq(expected, 0.05), "{current}");
+}
+
+#[test]
+fn smooth_damp_stops_at_the_target_instead_of_passing_it() {
+    let mut velocity = 1000.0;
+    assert_eq!(smooth_damp(0.0, 1.0, &mut velocity, 0.3, 0.1), 1.0);
+    assert_eq!(velocity, 0.0);
+
+    // Moving away at first still turns around and comes back
+    let mut current = 0.0;
+    let mut velocity = -20.0;
+    current = smooth_damp(current, 5.0, &mut velocity, 0.3, DELTA);
+    assert!(current < 0.0);
+    for _ in 0..256 {
+        current = smooth_damp(current, 5.0, &mut velocity, 0.3, DELTA);
+    }
+    assert!((current - 5.0).abs() < EPSILON);
+}
+
+#[test]
+fn smooth_damp_angles_keep_their_velocity_in_the_callers_units() {
+    let mut radians_velocity = 0.0;
+    let mut degrees_velocity = 0.0;
+    let mut radians = Radians::from_f32(170f32.to_radians());
+    let mut degrees = Degrees::from_f32(170.0);
+    for _ in 0..8 {
+        radians = smooth_damp_angle(
+            radians,
+            Radians::from_f32((-170f32).to_radians()),
+            &mut radians_velocity,
+            0.3,
+            DELTA,
+        );
+        degrees = smooth_damp_degrees(
+            degrees,
+            Degrees::from_f32(-170.0),
+            &mut degrees_velocity,
+            0.3,
+            DELTA,
+        );
+    }
+    assert!(radians.to_f32() > 170f32.to_radians());
+    assert!((radians.to_f32().to_degrees() - degrees.to_f32()).abs() < EPSILON);
+    assert!((radians_velocity.to_degrees() - degrees_velocity).abs() < EPSILON);
+}
+
+#[test]
+fn smoothing_components_follow_their_targets() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpatialBundle2D::default(),
+            SmoothPosition2D::new(
+                Position2D::new(8.0, 0.0),
+                SmoothMode2D::ExpDecay { rate: 2.0 },
+            ),
+            SmoothRotation2D::new(
+                Rotation2D::from_f32_degrees(-90.0),
+                SmoothMode2D::ExpDecay { rate: 1.0 },
+            ),
+            SmoothScale2D::new(
+                Scale2D::new(3.0, 0.5),
+                SmoothMode2D::SmoothDamp { smooth_time: 0.2 },
+            ),
+        ))
+        .id();
+    for _ in 0..64 {
+        app.update();
+    }
+
+    let position = Vec2::from(*app.world().get::<Position2D>(entity).unwrap());
+    assert!(position.abs_diff_eq(Vec2::new(8.0 * (1.0 - (-2.0f32).exp()), 0.0), EPSILON));
+    let rotation = app.world().get::<Rotation2D>(entity).unwrap();
+    let expected = -90.0 * (1.0 - (-1.0f32).exp());
+    assert!((rotation.degrees().to_f32() - expected).abs() < 0.01);
+    let scale = app.world().get::<Scale2D>(entity).unwrap();
+    assert!(Vec2::new(scale.x, scale.y).abs_diff_eq(Vec2::new(3.0, 0.5), 0.01));
+}
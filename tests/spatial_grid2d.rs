@@ -0,0 +1,227 @@
+mod common;
+
+use bevy::prelude::*;
+use common::app;
+use rantz_spatial2d::prelude::*;
+
+fn entity(index: u32) -> Entity {
+    Entity::from_raw(index)
+}
+
+fn sorted(mut entities: Vec<Entity>) -> Vec<Entity> {
+    entities.sort();
+    entities
+}
+
+#[test]
+fn inserted_points_are_found_by_radius_and_rect() {
+    let mut grid = SpatialGrid2D::new(10.0);
+    grid.insert_point(entity(0), Position2D::new(1.0, 1.0));
+    grid.insert_point(entity(1), Position2D::new(25.0, 5.0));
+    grid.insert_point(entity(2), Position2D::new(-40.0, -40.0));
+
+    assert_eq!(grid.len(), 3);
+    assert!(grid.contains(entity(1)));
+    assert_eq!(
+        sorted(grid.within_radius(Position2D::new(0.0, 0.0), 30.0)),
+        vec![entity(0), entity(1)]
+    );
+    assert_eq!(
+        grid.within_rect(Rect::new(20.0, 0.0, 30.0, 10.0)),
+        vec![entity(1)]
+    );
+    assert_eq!(
+        grid.within_radius_by(Position2D::new(0.0, 0.0), 30.0, |e| e != entity(0)),
+        vec![entity(1)]
+    );
+}
+
+#[test]
+fn moving_an_entry_changes_its_cell() {
+    let mut grid = SpatialGrid2D::new(10.0);
+    grid.insert_point(entity(0), Position2D::new(1.0, 1.0));
+    grid.insert_point(entity(0), Position2D::new(95.0, 95.0));
+
+    assert_eq!(grid.len(), 1);
+    assert!(grid
+        .within_radius(Position2D::new(0.0, 0.0), 5.0)
+        .is_empty());
+    assert_eq!(
+        grid.within_radius(Position2D::new(95.0, 95.0), 1.0),
+        vec![entity(0)]
+    );
+    assert_eq!(grid.position(entity(0)), Some(Position2D::new(95.0, 95.0)));
+    assert_eq!(
+        grid.occupied_cells(),
+        Some((IVec2::new(9, 9), IVec2::new(9, 9)))
+    );
+}
+
+#[test]
+fn removing_entries_shrinks_the_occupied_cells() {
+    let mut grid = SpatialGrid2D::new(10.0);
+    grid.insert_point(entity(0), Position2D::new(1.0, 1.0));
+    grid.insert_point(entity(1), Position2D::new(1000.0, -1000.0));
+    assert_eq!(
+        grid.occupied_cells(),
+        Some((IVec2::new(0, -100), IVec2::new(100, 0)))
+    );
+
+    assert!(grid.remove(entity(1)));
+    assert!(!grid.remove(entity(1)));
+    assert_eq!(
+        grid.occupied_cells(),
+        Some((IVec2::new(0, 0), IVec2::new(0, 0)))
+    );
+    assert!(grid
+        .within_radius(Position2D::new(1000.0, -1000.0), 1.0)
+        .is_empty());
+
+    assert!(grid.remove(entity(0)));
+    assert!(grid.is_empty());
+    assert_eq!(grid.occupied_cells(), None);
+}
+
+#[test]
+fn k_nearest_is_sorted_and_filtered() {
+    let mut grid = SpatialGrid2D::new(10.0);
+    let points = [
+        (0, Vec2::new(3.0, 0.0)),
+        (1, Vec2::new(-1.0, 0.0)),
+        (2, Vec2::new(0.0, 55.0)),
+        (3, Vec2::new(12.0, 12.0)),
+        (4, Vec2::new(-200.0, 0.0)),
+    ];
+    for (index, point) in points {
+        grid.insert_point(entity(index), point.into());
+    }
+
+    let center = Position2D::new(0.0, 0.0);
+    assert_eq!(
+        grid.k_nearest(center, 3),
+        vec![entity(1), entity(0), entity(3)]
+    );
+    assert_eq!(grid.k_nearest(center, 10).len(), 5);
+    assert_eq!(grid.k_nearest(center, 10)[4], entity(4));
+    assert_eq!(
+        grid.k_nearest_by(center, 2, |e| e != entity(1)),
+        vec![entity(0), entity(3)]
+    );
+    assert!(grid.k_nearest(center, 0).is_empty());
+}
+
+#[test]
+fn k_nearest_agrees_with_a_full_scan() {
+    let mut grid = SpatialGrid2D::new(8.0);
+    let mut seed = 7u32;
+    let mut next = || {
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (seed >> 8) as f32 / (1 << 24) as f32 * 200.0 - 100.0
+    };
+    let points: Vec<(Entity, Vec2)> = (0..150)
+        .map(|index| (entity(index), Vec2::new(next(), next())))
+        .collect();
+    for (entity, point) in &points {
+        grid.insert_point(*entity, (*point).into());
+    }
+
+    // Inside the occupied cells, just outside and far away on every side
+    for center in [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(-99.0, 42.0),
+        Vec2::new(130.0, -5.0),
+        Vec2::new(-4000.0, 9000.0),
+        Vec2::new(1.0e7, 1.0e7),
+    ] {
+        for k in [1, 4, 25] {
+            let mut expected: Vec<f32> = points
+                .iter()
+                .map(|(_, point)| point.distance(center))
+                .collect();
+            expected.sort_by(f32::total_cmp);
+            let found: Vec<f32> = grid
+                .k_nearest(center.into(), k)
+                .into_iter()
+                .map(|entity| Vec2::from(grid.position(entity).unwrap()).distance(center))
+                .collect();
+            assert_eq!(found, expected[..k], "{k} nearest to {center}");
+        }
+    }
+}
+
+#[test]
+fn k_nearest_handles_cells_at_the_ends_of_the_range() {
+    let mut grid = SpatialGrid2D::new(1.0);
+    grid.insert_point(entity(0), Position2D::new(-3.0e11, -3.0e11));
+    grid.insert_point(entity(1), Position2D::new(3.0e11, 3.0e11));
+    grid.insert_point(entity(2), Position2D::new(2.0e11, 3.0e11));
+
+    assert_eq!(
+        grid.k_nearest(Position2D::new(1.0e12, 1.0e12), 2),
+        vec![entity(1), entity(2)]
+    );
+    assert_eq!(
+        grid.k_nearest(Position2D::new(-1.0e12, 0.0), 3),
+        vec![entity(0), entity(2), entity(1)]
+    );
+}
+
+#[test]
+fn despawned_entities_leave_the_index() {
+    let mut app = app();
+    app.insert_resource(SpatialGrid2D::new(10.0));
+    let entity = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(12.0, 3.0),
+            ..default()
+        })
+        .id();
+    app.update();
+    assert!(app.world().resource::<SpatialGrid2D>().contains(entity));
+
+    app.world_mut().despawn(entity);
+    app.update();
+    assert!(app.world().resource::<SpatialGrid2D>().is_empty());
+}
+
+#[test]
+fn a_replaced_index_picks_up_resting_entities() {
+    let mut app = app();
+    app.insert_resource(SpatialGrid2D::new(10.0));
+    let entity = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(12.0, 3.0),
+            ..default()
+        })
+        .id();
+    app.update();
+    app.update();
+
+    // Nothing moves after the swap, so change detection alone would leave it empty
+    app.insert_resource(SpatialGrid2D::new(50.0));
+    app.update();
+    let grid = app.world().resource::<SpatialGrid2D>();
+    assert_eq!(grid.cell_size(), 50.0);
+    assert_eq!(grid.position(entity), Some(Position2D::new(12.0, 3.0)));
+}
+
+#[test]
+fn an_index_added_late_picks_up_resting_entities() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(SpatialBundle2D {
+            position: Position2D::new(12.0, 3.0),
+            ..default()
+        })
+        .id();
+    app.update();
+    app.update();
+
+    app.insert_resource(SpatialGrid2D::new(10.0));
+    app.update();
+    let grid = app.world().resource::<SpatialGrid2D>();
+    assert_eq!(grid.position(entity), Some(Position2D::new(12.0, 3.0)));
+}
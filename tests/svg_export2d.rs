@@ -0,0 +1,91 @@
+#![cfg(feature = "testing")]
+
+mod common;
+
+use bevy::prelude::*;
+use common::named;
+use rantz_spatial2d::prelude::*;
+
+fn golden(name: &str) -> String {
+    format!(
+        "{}/tests/fixtures/golden/{name}",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn ship() -> TestNode2D {
+    TestNode2D::new("ship")
+        .rotated(30.0)
+        .scaled(2.0, 2.0)
+        .with_child(
+            TestNode2D::new("turret")
+                .at(8.0, 0.0)
+                .with_draw_order(1.0)
+                .with_child(TestNode2D::new("barrel").at(6.0, 0.0).rotated(-30.0)),
+        )
+}
+
+fn rock() -> TestNode2D {
+    TestNode2D::new("rock & <ore>").at(-40.0, 20.0)
+}
+
+// Bounds need a frame to be picked up and another to reach the hierarchy
+fn render(app: &mut App, hierarchy: TestHierarchy2D, export: SvgExport2D) -> String {
+    hierarchy.spawn(app.world_mut());
+    let ship = named(app, "ship");
+    app.world_mut()
+        .entity_mut(ship)
+        .insert(Bounds2D::rect(Vec2::new(16.0, 8.0)));
+    let rock = named(app, "rock & <ore>");
+    app.world_mut()
+        .entity_mut(rock)
+        .insert(Bounds2D::circle(6.0));
+    app.update();
+    app.update();
+    export.render(app.world_mut())
+}
+
+fn fleet() -> TestHierarchy2D {
+    TestHierarchy2D::new().with_root(ship()).with_root(rock())
+}
+
+#[test]
+fn fleet_matches_golden_svg() {
+    let svg = render(&mut TestHierarchy2D::app(), fleet(), SvgExport2D::default());
+    assert_golden_text(golden("fleet.svg"), &svg);
+}
+
+#[test]
+fn output_does_not_depend_on_entity_ids() {
+    let svg = render(&mut TestHierarchy2D::app(), fleet(), SvgExport2D::default());
+
+    // Other entities first and the roots the other way round shift every index
+    let mut shifted = TestHierarchy2D::app();
+    for _ in 0..7 {
+        shifted.world_mut().spawn_empty();
+    }
+    let reversed = TestHierarchy2D::new().with_root(rock()).with_root(ship());
+    assert_eq!(render(&mut shifted, reversed, SvgExport2D::default()), svg);
+    assert_eq!(SvgExport2D::default().render(shifted.world_mut()), svg);
+}
+
+#[test]
+fn labels_and_bounds_can_be_left_out() {
+    let full = render(&mut TestHierarchy2D::app(), fleet(), SvgExport2D::default());
+    assert!(full.contains(r#"<g data-path="ship/turret/barrel">"#));
+    assert!(full.contains(">rock &amp; &lt;ore&gt;</text>"));
+    assert!(full.contains("<rect ") && full.contains("<circle "));
+
+    let bare = render(
+        &mut TestHierarchy2D::app(),
+        fleet(),
+        SvgExport2D::default()
+            .without_labels()
+            .without_bounds()
+            .without_hierarchy(),
+    );
+    for element in ["<text", "<rect ", "<circle ", "<line "] {
+        assert!(!bare.contains(element), "{element} in {bare}");
+    }
+    assert_eq!(bare.matches("<g data-path=").count(), 4);
+}
@@ -0,0 +1,341 @@
+#![cfg(feature = "tiled")]
+
+mod common;
+
+use bevy::prelude::*;
+use common::{app, named, position, world_translation};
+use rantz_spatial2d::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/tiled/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn spawn(app: &mut App, name: &str) -> Entity {
+    let root = TiledDocument2D::load(fixture(name))
+        .unwrap()
+        .spawn(app.world_mut());
+    app.update();
+    root
+}
+
+struct Tile {
+    coord: GridCoord,
+    position: Vec2,
+    rotation: Rotation2D,
+    scale: Scale2D,
+    tile: TiledTile2D,
+}
+
+// In the order they were spawned, which is the order of the layer data
+fn tiles(app: &mut App, layer: &str) -> Vec<Tile> {
+    let layer = named(app, layer);
+    let world = app.world_mut();
+    let mut tiles: Vec<(Entity, Tile)> = world
+        .query::<(
+            Entity,
+            &Parent,
+            &GridCoord,
+            &Position2D,
+            &Rotation2D,
+            &Scale2D,
+            &TiledTile2D,
+        )>()
+        .iter(world)
+        .filter(|(_, parent, ..)| parent.get() == layer)
+        .map(|(entity, _, coord, position, rotation, scale, tile)| {
+            let tile = Tile {
+                coord: *coord,
+                position: (*position).into(),
+                rotation: *rotation,
+                scale: *scale,
+                tile: *tile,
+            };
+            (entity, tile)
+        })
+        .collect();
+    tiles.sort_by_key(|(entity, _)| *entity);
+    tiles.into_iter().map(|(_, tile)| tile).collect()
+}
+
+fn degrees(app: &App, entity: Entity) -> f32 {
+    app.world()
+        .get::<Rotation2D>(entity)
+        .unwrap()
+        .degrees()
+        .to_f32()
+}
+
+fn assert_close(a: Vec2, b: Vec2) {
+    assert!(a.abs_diff_eq(b, EPSILON), "{a} != {b}");
+}
+
+#[test]
+fn finite_maps_put_the_bottom_left_corner_at_the_origin() {
+    let mut app = app();
+    let root = spawn(&mut app, "finite.tmj");
+    assert_eq!(
+        app.world().get::<Name>(root).map(Name::as_str),
+        Some("finite")
+    );
+    assert_eq!(
+        app.world().get::<TiledMap2D>(root),
+        Some(&TiledMap2D {
+            size: UVec2::new(8, 2),
+            tile_size: Vec2::splat(16.0),
+        })
+    );
+
+    let ground = tiles(&mut app, "Ground");
+    let found: Vec<_> = ground
+        .iter()
+        .map(|tile| (tile.tile.gid, tile.coord, tile.position))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (1, GridCoord::new(0, 1), Vec2::new(8.0, 24.0)),
+            (2, GridCoord::new(2, 1), Vec2::new(40.0, 24.0)),
+            (3, GridCoord::new(1, 0), Vec2::new(24.0, 8.0)),
+        ]
+    );
+    // Grid coordinates count up from the bottom row, like the positions
+    for tile in &ground {
+        let center = (Vec2::new(tile.coord.x as f32, tile.coord.y as f32) + 0.5) * 16.0;
+        assert_close(tile.position, center);
+    }
+}
+
+#[test]
+fn infinite_maps_keep_tileds_origin() {
+    let mut app = app();
+    let root = spawn(&mut app, "infinite.tmj");
+    assert_eq!(
+        app.world().get::<TiledMap2D>(root).map(|map| map.size),
+        Some(UVec2::ZERO)
+    );
+
+    let found: Vec<_> = tiles(&mut app, "Chunks")
+        .iter()
+        .map(|tile| (tile.tile.gid, tile.coord, tile.position))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (1, GridCoord::new(-2, 1), Vec2::new(-24.0, 24.0)),
+            (2, GridCoord::new(-1, 0), Vec2::new(-8.0, 8.0)),
+            (3, GridCoord::new(1, -1), Vec2::new(24.0, -8.0)),
+        ]
+    );
+}
+
+#[test]
+fn every_flip_combination_matches_tileds_transform() {
+    let mut app = app();
+    spawn(&mut app, "finite.tmj");
+    let flips = tiles(&mut app, "Flips");
+    assert_eq!(flips.len(), 8);
+
+    // Tiled mirrors the diagonal first, then x, then y. In y-up space the diagonal mirror
+    // sends x to -y and y to -x.
+    let diagonal = Mat2::from_cols(Vec2::new(0.0, -1.0), Vec2::new(-1.0, 0.0));
+    for (index, tile) in flips.iter().enumerate() {
+        let (flip_x, flip_y, flip_diagonal) = (index & 1 != 0, index & 2 != 0, index & 4 != 0);
+        assert_eq!(
+            tile.tile,
+            TiledTile2D {
+                gid: 5,
+                flip_x,
+                flip_y,
+                flip_diagonal,
+            }
+        );
+        let mut expected = if flip_diagonal {
+            diagonal
+        } else {
+            Mat2::IDENTITY
+        };
+        if flip_x {
+            expected = Mat2::from_diagonal(Vec2::new(-1.0, 1.0)) * expected;
+        }
+        if flip_y {
+            expected = Mat2::from_diagonal(Vec2::new(1.0, -1.0)) * expected;
+        }
+        let actual = Mat2::from_angle(tile.rotation.radians().to_f32())
+            * Mat2::from_diagonal(Vec2::new(tile.scale.x, tile.scale.y));
+        assert!(
+            actual.abs_diff_eq(expected, EPSILON),
+            "flip {index}: {actual} != {expected}"
+        );
+        // Flipping never moves the tile off its cell
+        assert_close(tile.position, Vec2::new(index as f32 * 16.0 + 8.0, 24.0));
+    }
+}
+
+#[test]
+fn objects_are_centred_and_rotate_about_tileds_anchor() {
+    let mut app = app();
+    spawn(&mut app, "finite.tmj");
+
+    // A rect hangs from its top left corner, a quarter turn clockwise swings it left of it
+    let rect = named(&mut app, "rect");
+    assert_close(position(&app, rect), Vec2::new(14.0, 20.0));
+    assert!((degrees(&app, rect) + 90.0).abs() < EPSILON);
+    let object = app.world().get::<TiledObject2D>(rect).unwrap();
+    assert_eq!(
+        (object.id, object.class.as_str(), object.size),
+        (1, "door", Vec2::new(8.0, 4.0))
+    );
+    assert_eq!(object.shape, TiledShape2D::Rect);
+    assert_eq!(
+        app.world().get::<Bounds2D>(rect),
+        Some(&Bounds2D::rect(Vec2::new(8.0, 4.0)))
+    );
+
+    // Tile objects hang from their bottom left corner instead
+    let tile = named(&mut app, "tile");
+    assert_close(position(&app, tile), Vec2::new(40.0, 8.0));
+    assert!((degrees(&app, tile) + 90.0).abs() < EPSILON);
+    assert_eq!(app.world().get::<TiledObject2D>(tile).unwrap().gid, Some(1));
+
+    // Points and polygons sit on their anchor, only turning
+    let point = named(&mut app, "point");
+    assert_close(position(&app, point), Vec2::new(4.0, 28.0));
+    assert!((degrees(&app, point) + 45.0).abs() < EPSILON);
+    assert_eq!(
+        app.world().get::<TiledObject2D>(point).unwrap().shape,
+        TiledShape2D::Point
+    );
+
+    let polygon = named(&mut app, "polygon");
+    assert_close(position(&app, polygon), Vec2::new(48.0, 16.0));
+    assert!((degrees(&app, polygon) + 30.0).abs() < EPSILON);
+    assert_eq!(
+        app.world().get::<TiledObject2D>(polygon).unwrap().shape,
+        TiledShape2D::Polygon(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(8.0, 0.0),
+            Vec2::new(0.0, -8.0),
+        ])
+    );
+    assert_eq!(
+        app.world().get::<Visibility>(polygon),
+        Some(&Visibility::Hidden)
+    );
+}
+
+#[test]
+fn groups_nest_offsets_and_draw_order() {
+    let mut app = app();
+    spawn(&mut app, "finite.tmj");
+
+    let z = |app: &mut App, name: &str| {
+        let entity = named(app, name);
+        world_translation(app, entity).z
+    };
+    // Layers are one step apart, everything inside a layer stays inside its step
+    for (name, expected) in [
+        ("Ground", 0.0),
+        ("Flips", 1.0),
+        ("Objects", 2.0),
+        ("point", 2.0),
+        ("rect", 2.25),
+        ("tile", 2.5),
+        ("polygon", 2.75),
+        ("Group", 3.0),
+        ("Nested", 3.0),
+        ("Ordered", 3.5),
+        ("below", 3.5),
+        ("above", 3.75),
+    ] {
+        assert!(
+            (z(&mut app, name) - expected).abs() < EPSILON,
+            "{name} is at {}",
+            z(&mut app, name)
+        );
+    }
+    let flips = named(&mut app, "Flips");
+    assert_eq!(
+        app.world().get::<TiledLayer2D>(flips),
+        Some(&TiledLayer2D {
+            id: 2,
+            kind: TiledLayerKind2D::Tiles,
+            opacity: 0.5,
+        })
+    );
+
+    // Tiled's offsets are y-down
+    let group = named(&mut app, "Group");
+    assert_close(position(&app, group), Vec2::new(8.0, -8.0));
+    let nested = tiles(&mut app, "Nested");
+    assert_eq!(nested.len(), 1);
+    assert_eq!(nested[0].coord, GridCoord::new(1, 0));
+    let below = named(&mut app, "below");
+    assert_close(
+        world_translation(&app, below).truncate(),
+        Vec2::new(9.0, -7.0),
+    );
+    let above = named(&mut app, "above");
+    assert_close(position(&app, above), Vec2::new(2.0, 28.0));
+    assert_eq!(
+        app.world().get::<Bounds2D>(above),
+        Some(&Bounds2D::circle(2.0))
+    );
+}
+
+#[test]
+fn pixels_per_unit_scales_everything_placed() {
+    let mut app = app();
+    let document = TiledDocument2D::load(fixture("finite.tmj"))
+        .unwrap()
+        .with_name("scaled")
+        .with_pixels_per_unit(16.0);
+    assert_eq!(document.tile_size(), Vec2::ONE);
+    let root = document.spawn(app.world_mut());
+    app.update();
+    assert_eq!(
+        app.world().get::<Name>(root).map(Name::as_str),
+        Some("scaled")
+    );
+
+    let ground = tiles(&mut app, "Ground");
+    assert_close(ground[0].position, Vec2::new(0.5, 1.5));
+    let rect = named(&mut app, "rect");
+    assert_close(position(&app, rect), Vec2::new(14.0, 20.0) / 16.0);
+}
+
+fn unsupported(text: &str) -> String {
+    match TiledDocument2D::from_json(text) {
+        Err(TiledImportError::Unsupported(what)) => what,
+        other => panic!("expected an unsupported map, got {other:?}"),
+    }
+}
+
+#[test]
+fn unsupported_maps_are_refused() {
+    let map = |orientation: &str, layers: &str| {
+        format!(
+            r#"{{"orientation": "{orientation}", "width": 2, "height": 2,
+                "tilewidth": 16, "tileheight": 16, "layers": [{layers}]}}"#
+        )
+    };
+    for orientation in ["isometric", "staggered", "hexagonal"] {
+        assert!(unsupported(&map(orientation, "")).contains(orientation));
+    }
+
+    let base64 = r#"{"name": "Encoded", "type": "tilelayer", "width": 2,
+        "encoding": "base64", "data": "AQAAAAAAAAAAAAAAAgAAAA=="}"#;
+    assert!(unsupported(&map("orthogonal", base64)).contains("Encoded"));
+    let chunked = r#"{"name": "Chunked", "type": "tilelayer", "encoding": "base64",
+        "chunks": [{"x": 0, "y": 0, "width": 1, "height": 1, "data": "AQAAAA=="}]}"#;
+    assert!(unsupported(&map("orthogonal", chunked)).contains("Chunked"));
+    let grouped = format!(r#"{{"name": "Outer", "type": "group", "layers": [{base64}]}}"#);
+    assert!(unsupported(&map("orthogonal", &grouped)).contains("Encoded"));
+
+    assert!(TiledDocument2D::from_json(&map("orthogonal", "")).is_ok());
+    assert!(matches!(
+        TiledDocument2D::from_json("{}"),
+        Err(TiledImportError::Json(_))
+    ));
+}
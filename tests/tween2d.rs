@@ -0,0 +1,181 @@
+mod common;
+
+use bevy::prelude::*;
+use common::manual_time;
+use rantz_spatial2d::prelude::*;
+use std::time::Duration;
+
+const EPSILON: f32 = 1e-3;
+
+fn app() -> App {
+    let mut app = common::app();
+    app.add_plugins(TweenPlugin2D);
+    app
+}
+
+fn seconds(seconds: f32) -> Duration {
+    Duration::from_secs_f32(seconds)
+}
+
+// Three sides of a square, each step starting from wherever the last one ended
+fn square() -> Tween2D<PositionLens> {
+    Tween2D::new(
+        PositionLens::to(Position2D::new(10.0, 0.0)),
+        seconds(1.0),
+        Easing::Linear,
+    )
+    .then(
+        PositionLens::to(Position2D::new(10.0, 10.0)),
+        seconds(1.0),
+        Easing::Linear,
+    )
+    .then(
+        PositionLens::to(Position2D::new(0.0, 10.0)),
+        seconds(1.0),
+        Easing::Linear,
+    )
+}
+
+fn tick(
+    tween: &mut Tween2D<PositionLens>,
+    position: &mut Position2D,
+    delta: f32,
+) -> Vec<TweenEventKind2D> {
+    let mut events = Vec::new();
+    tween.tick(seconds(delta), position, |kind| events.push(kind));
+    events
+}
+
+fn assert_position(position: Position2D, expected: Vec2) {
+    assert!(
+        Vec2::from(position).abs_diff_eq(expected, EPSILON),
+        "{position:?} != {expected}"
+    );
+}
+
+#[test]
+fn skipped_steps_still_finish_in_order() {
+    let mut tween = square();
+    let mut position = Position2D::default();
+    assert_eq!(
+        tick(&mut tween, &mut position, 2.5),
+        vec![
+            TweenEventKind2D::StepCompleted(0),
+            TweenEventKind2D::StepCompleted(1),
+        ]
+    );
+    // The last step starts at the corner the skipped ones reached
+    assert_position(position, Vec2::new(5.0, 10.0));
+}
+
+#[test]
+fn one_long_tick_completes_every_step() {
+    let mut tween = square();
+    let mut position = Position2D::default();
+    assert_eq!(
+        tick(&mut tween, &mut position, 10.0),
+        vec![
+            TweenEventKind2D::StepCompleted(0),
+            TweenEventKind2D::StepCompleted(1),
+            TweenEventKind2D::StepCompleted(2),
+            TweenEventKind2D::LoopCompleted,
+            TweenEventKind2D::Completed,
+        ]
+    );
+    assert!(tween.is_finished());
+    assert_position(position, Vec2::new(0.0, 10.0));
+}
+
+#[test]
+fn wrapping_loops_finish_the_steps_left_behind() {
+    let mut tween = square().with_repeat(TweenRepeat2D::Times(2));
+    let mut position = Position2D::default();
+    assert!(tick(&mut tween, &mut position, 0.5).is_empty());
+    assert_eq!(
+        tick(&mut tween, &mut position, 3.75),
+        vec![
+            TweenEventKind2D::StepCompleted(0),
+            TweenEventKind2D::StepCompleted(1),
+            TweenEventKind2D::StepCompleted(2),
+            TweenEventKind2D::LoopCompleted,
+            TweenEventKind2D::StepCompleted(0),
+        ]
+    );
+    assert_position(position, Vec2::new(10.0, 2.5));
+}
+
+#[test]
+fn yoyo_loops_finish_steps_at_their_start() {
+    let mut tween = square()
+        .with_repeat(TweenRepeat2D::Times(2))
+        .with_yoyo(true);
+    let mut position = Position2D::default();
+    assert_eq!(
+        tick(&mut tween, &mut position, 4.5),
+        vec![
+            TweenEventKind2D::StepCompleted(0),
+            TweenEventKind2D::StepCompleted(1),
+            TweenEventKind2D::StepCompleted(2),
+            TweenEventKind2D::LoopCompleted,
+            TweenEventKind2D::StepCompleted(2),
+        ]
+    );
+    assert_position(position, Vec2::new(10.0, 5.0));
+}
+
+#[test]
+fn huge_ticks_only_play_out_the_last_loops() {
+    for (yoyo, expected) in [(false, Vec2::new(5.0, 0.0)), (true, Vec2::new(5.0, 10.0))] {
+        let mut tween = square().with_repeat(TweenRepeat2D::Forever).with_yoyo(yoyo);
+        let mut position = Position2D::default();
+        tick(&mut tween, &mut position, 0.5);
+        // Into the middle of loop 1000001, an odd one that plays backwards with yoyo
+        let events = tick(&mut tween, &mut position, 3.0 * 1_000_001.0);
+
+        let loops = events
+            .iter()
+            .filter(|kind| **kind == TweenEventKind2D::LoopCompleted)
+            .count();
+        // The loop in progress, then the last 64
+        assert_eq!(loops, 65);
+        assert!(events.len() <= 65 * 4, "{}", events.len());
+        assert!(!tween.is_finished());
+        assert_position(position, expected);
+    }
+}
+
+#[test]
+fn tweens_run_on_components_and_send_events() {
+    let mut app = app();
+    // Long frames are cut to a quarter second otherwise
+    app.world_mut()
+        .resource_mut::<Time<Virtual>>()
+        .set_max_delta(seconds(10.0));
+    manual_time(&mut app, 2.5);
+    let entity = app
+        .world_mut()
+        .spawn((SpatialBundle2D::default(), square()))
+        .id();
+    app.update();
+
+    assert_position(
+        *app.world().get::<Position2D>(entity).unwrap(),
+        Vec2::new(5.0, 10.0),
+    );
+    let events = app.world().resource::<Events<TweenEvent2D>>();
+    let kinds: Vec<_> = events
+        .get_reader()
+        .read(events)
+        .map(|event| {
+            assert_eq!(event.entity, entity);
+            event.kind
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TweenEventKind2D::StepCompleted(0),
+            TweenEventKind2D::StepCompleted(1),
+        ]
+    );
+}